@@ -0,0 +1,90 @@
+/*
+ * Android related checks
+ */
+use regex::Regex;
+use std::env;
+use std::process::Command;
+use utils;
+
+const TERMUX_PREFIX: &str = "/data/data/com.termux/files/usr";
+
+pub struct BuildProp {
+    pub release: Option<String>,
+    pub sdk: Option<String>,
+    pub manufacturer: Option<String>,
+    pub security_patch: Option<String>
+}
+
+/// Holds Android specific release information
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AndroidRelease {
+    /// The API level, e.g. `34` for Android 14
+    pub api_level: Option<u32>,
+    pub manufacturer: Option<String>,
+    /// The security patch level, e.g. `2024-03-05`
+    pub security_patch: Option<String>,
+    /// `true` if the process runs inside the Termux app
+    pub termux: bool,
+}
+
+pub fn is_available() -> bool {
+    utils::file_exists("/system/build.prop")
+}
+
+pub fn retrieve() -> Option<BuildProp> {
+    if let Ok(build_prop) = utils::read_file("/system/build.prop") {
+        return Some(parse(build_prop));
+    }
+
+    let output = match Command::new("getprop").output() {
+        Ok(o) => o,
+        Err(_) => return None
+    };
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(parse_getprop(stdout.to_string()))
+}
+
+/// Parses `/system/build.prop`, which consists of `key=value` lines
+pub fn parse(file: String) -> BuildProp {
+    let mut properties = utils::parse_key_values(&file);
+
+    BuildProp {
+        release: properties.remove("ro.build.version.release"),
+        sdk: properties.remove("ro.build.version.sdk"),
+        manufacturer: properties.remove("ro.product.manufacturer"),
+        security_patch: properties.remove("ro.build.version.security_patch")
+    }
+}
+
+/// Parses the output of `getprop`, which prints `[key]: [value]` lines
+pub fn parse_getprop(output: String) -> BuildProp {
+    let property_regex = Regex::new(r"(?m)^\s*\[([^\]]+)\]:\s*\[([^\]]*)\]").unwrap();
+
+    let build_prop = property_regex.captures_iter(&output)
+        .map(|m| format!("{}={}", &m[1], &m[2]))
+        .collect::<Vec<_>>()
+        .join("\n");
+    parse(build_prop)
+}
+
+/// Returns `true` if the given installation prefix is the one Termux
+/// installs its packages into
+pub fn is_termux_prefix(prefix: &str) -> bool {
+    prefix.trim_end_matches('/') == TERMUX_PREFIX
+}
+
+pub fn is_termux() -> bool {
+    if let Ok(prefix) = env::var("PREFIX") {
+        if is_termux_prefix(&prefix) {
+            return true;
+        }
+    }
+    match env::current_exe() {
+        Ok(exe) => exe.starts_with(TERMUX_PREFIX),
+        Err(_) => false
+    }
+}
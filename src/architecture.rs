@@ -0,0 +1,152 @@
+/*
+ * CPU architecture related checks
+ */
+use elf;
+use std::path::Path;
+use uname;
+use utils;
+
+/// A CPU architecture, normalized from the various names in use, e.g.
+/// `x86_64` and `amd64`
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Architecture {
+    X86,
+    #[cfg_attr(feature = "serde", serde(rename = "x86_64"))]
+    X86_64,
+    Arm,
+    Aarch64,
+    #[cfg_attr(feature = "serde", serde(rename = "powerpc"))]
+    PowerPC,
+    #[cfg_attr(feature = "serde", serde(rename = "powerpc64"))]
+    PowerPC64,
+    #[cfg_attr(feature = "serde", serde(rename = "riscv32"))]
+    RiscV32,
+    #[cfg_attr(feature = "serde", serde(rename = "riscv64"))]
+    RiscV64,
+    S390x,
+    Mips,
+    Mips64,
+    #[cfg_attr(feature = "serde", serde(rename = "loongarch64"))]
+    LoongArch64,
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Other(String),
+}
+
+impl Architecture {
+    /// Normalizes a `uname -m` machine name or a Debian architecture name
+    pub fn from_name(name: &str) -> Architecture {
+        match name.trim() {
+            "x86_64" | "amd64" | "x64" => Architecture::X86_64,
+            "i386" | "i486" | "i586" | "i686" | "x86" => Architecture::X86,
+            "aarch64" | "arm64" | "aarch64_be" => Architecture::Aarch64,
+            "arm" | "armel" | "armhf" | "armv6l" | "armv7l" | "armv8l" => Architecture::Arm,
+            "ppc" | "powerpc" => Architecture::PowerPC,
+            "ppc64" | "ppc64le" | "ppc64el" => Architecture::PowerPC64,
+            "riscv32" => Architecture::RiscV32,
+            "riscv64" => Architecture::RiscV64,
+            "s390x" => Architecture::S390x,
+            "mips" | "mipsel" => Architecture::Mips,
+            "mips64" | "mips64el" => Architecture::Mips64,
+            "loongarch64" | "loong64" => Architecture::LoongArch64,
+            other => Architecture::Other(other.to_owned()),
+        }
+    }
+
+    /// Returns the word size of the architecture, if known
+    pub fn bits(&self) -> Option<u32> {
+        match *self {
+            Architecture::X86
+            | Architecture::Arm
+            | Architecture::PowerPC
+            | Architecture::RiscV32
+            | Architecture::Mips => Some(32),
+            Architecture::X86_64
+            | Architecture::Aarch64
+            | Architecture::PowerPC64
+            | Architecture::RiscV64
+            | Architecture::S390x
+            | Architecture::Mips64
+            | Architecture::LoongArch64 => Some(64),
+            Architecture::Other(_) => None,
+        }
+    }
+}
+
+/// Holds the kernel and userland architecture, which differ e.g. on a
+/// Raspberry Pi running a 64-bit kernel with a 32-bit armhf userland
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArchitectureInfo {
+    /// The machine name reported by the kernel, e.g. `aarch64`
+    pub machine: Option<String>,
+    pub kernel: Option<Architecture>,
+    /// The architecture programs are built for, from dpkg or `/bin/sh`
+    pub userland: Option<Architecture>,
+    /// The native Debian architecture, e.g. `armhf`. dpkg only records it
+    /// once foreign architectures have been added
+    pub dpkg_architecture: Option<String>,
+}
+
+impl ArchitectureInfo {
+    /// Returns `true` for a 32-bit userland running on a 64-bit kernel
+    pub fn is_32bit_userland_on_64bit_kernel(&self) -> bool {
+        let kernel_bits = self.kernel.as_ref().and_then(Architecture::bits);
+        let userland_bits = self.userland.as_ref().and_then(Architecture::bits);
+        kernel_bits == Some(64) && userland_bits == Some(32)
+    }
+}
+
+pub fn retrieve(uname: Option<&uname::Uname>) -> ArchitectureInfo {
+    detect(Path::new("/"), uname.and_then(|uname| uname.machine.clone()))
+}
+
+/// Determines the userland architecture below `root`, given the machine
+/// name the kernel reported
+pub fn detect(root: &Path, machine: Option<String>) -> ArchitectureInfo {
+    let dpkg_architecture = utils::read_file(utils::rooted(root, "/var/lib/dpkg/arch"))
+        .ok()
+        .and_then(|file| parse_dpkg_arch(&file));
+    let userland = match dpkg_architecture {
+        Some(ref name) => Some(Architecture::from_name(name)),
+        None => elf::read_header(utils::rooted(root, "/bin/sh")).and_then(|header| from_elf(&header)),
+    };
+
+    ArchitectureInfo {
+        kernel: machine.as_ref().map(|machine| Architecture::from_name(machine)),
+        machine,
+        userland,
+        dpkg_architecture,
+    }
+}
+
+/// Returns the native architecture from `/var/lib/dpkg/arch`. The first
+/// line is the native one, foreign architectures follow.
+pub fn parse_dpkg_arch(file: &str) -> Option<String> {
+    file.lines()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())
+        .map(|line| line.to_owned())
+}
+
+/// Maps the machine and class of an ELF header to an architecture
+pub fn from_elf(header: &elf::ElfHeader) -> Option<Architecture> {
+    let architecture = match (header.machine, header.bits) {
+        (elf::EM_386, _) => Architecture::X86,
+        (elf::EM_X86_64, 64) => Architecture::X86_64,
+        (elf::EM_ARM, _) => Architecture::Arm,
+        (elf::EM_AARCH64, _) => Architecture::Aarch64,
+        (elf::EM_PPC, _) => Architecture::PowerPC,
+        (elf::EM_PPC64, _) => Architecture::PowerPC64,
+        (elf::EM_RISCV, 32) => Architecture::RiscV32,
+        (elf::EM_RISCV, _) => Architecture::RiscV64,
+        (elf::EM_S390, 64) => Architecture::S390x,
+        (elf::EM_MIPS, 32) => Architecture::Mips,
+        (elf::EM_MIPS, _) => Architecture::Mips64,
+        (elf::EM_LOONGARCH, _) => Architecture::LoongArch64,
+        _ => return None,
+    };
+    Some(architecture)
+}
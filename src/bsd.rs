@@ -0,0 +1,86 @@
+/*
+ * BSD related checks
+ */
+use regex::Regex;
+use std::process::Command;
+use utils;
+
+/// A BSD release string such as `13.2-RELEASE-p4`, split into its parts
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BSDVersion {
+    /// The numeric version, e.g. `13.2`
+    pub version: String,
+    /// The branch, e.g. `RELEASE`, `STABLE` or `CURRENT`
+    pub branch: Option<String>,
+    /// The security patch level, e.g. `4` for `-p4`
+    pub patch_level: Option<u32>,
+}
+
+/// Holds BSD specific release information. FreeBSD can patch the kernel
+/// and the userland separately, so both are reported. Other BSDs only
+/// report the kernel.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BSDRelease {
+    pub kernel: Option<BSDVersion>,
+    pub userland: Option<BSDVersion>,
+}
+
+pub struct FreeBSDVersion {
+    pub kernel: Option<String>,
+    pub userland: Option<String>
+}
+
+pub fn retrieve_freebsd_version() -> Option<FreeBSDVersion> {
+    let output = match Command::new("freebsd-version").arg("-ku").output() {
+        Ok(o) => o,
+        Err(_) => return None
+    };
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(parse_freebsd_version(stdout.to_string()))
+}
+
+/// Parses the output of `freebsd-version -ku`, which prints the installed
+/// kernel version on the first and the userland version on the second line
+pub fn parse_freebsd_version(output: String) -> FreeBSDVersion {
+    let mut lines = output.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_owned());
+
+    FreeBSDVersion {
+        kernel: lines.next(),
+        userland: lines.next()
+    }
+}
+
+/// Reads the `VERSION` field of FreeBSD's os-release, e.g. `13.2-RELEASE-p4`.
+/// The file is generated at boot as `/var/run/os-release`, with
+/// `/etc/os-release` linking to it.
+pub fn retrieve_os_release_version() -> Option<String> {
+    ["/etc/os-release", "/var/run/os-release"].iter()
+        .filter_map(|path| utils::read_file(path).ok())
+        .next()
+        .and_then(|file| parse_os_release_version(&file))
+}
+
+pub fn parse_os_release_version(file: &str) -> Option<String> {
+    utils::parse_key_values(file).remove("VERSION")
+}
+
+/// Parses a release string like `13.2-RELEASE-p4`, `6.4-RELEASE`,
+/// `10.0_RC1` or `7.4`
+pub fn parse_version(release: &str) -> Option<BSDVersion> {
+    let version_regex = Regex::new(r"^(\d+(?:\.\d+)*)(?:[-_]([A-Z]+\d*))?(?:-p(\d+))?").unwrap();
+
+    let captures = version_regex.captures(release.trim())?;
+    Some(BSDVersion {
+        version: captures.get(1)?.as_str().to_owned(),
+        branch: captures.get(2).map(|branch| branch.as_str().to_owned()),
+        patch_level: captures.get(3).and_then(|patch| patch.as_str().parse().ok()),
+    })
+}
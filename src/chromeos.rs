@@ -0,0 +1,69 @@
+/*
+ * ChromeOS related checks
+ */
+use utils;
+
+pub struct ChromeOSLsbRelease {
+    pub version: Option<String>,
+    pub milestone: Option<String>,
+    pub board: Option<String>,
+    pub track: Option<String>
+}
+
+/// Holds ChromeOS specific release information. Inside a Crostini
+/// container only the host milestone is known.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChromeOSRelease {
+    /// The Chrome milestone, e.g. `117`
+    pub milestone: Option<u32>,
+    pub board: Option<String>,
+    /// The release channel, e.g. `stable-channel`
+    pub track: Option<String>,
+    /// `true` if this is a Linux guest running inside Crostini
+    pub crostini: bool,
+}
+
+pub fn is_available() -> bool {
+    match utils::read_file("/etc/lsb-release") {
+        Ok(file) => file.contains("CHROMEOS_RELEASE_"),
+        Err(_) => false
+    }
+}
+
+pub fn retrieve() -> Option<ChromeOSLsbRelease> {
+    match utils::read_file("/etc/lsb-release") {
+        Ok(file) => Some(parse(file)),
+        Err(_) => None
+    }
+}
+
+/// Parses the `CHROMEOS_RELEASE_*` keys of `/etc/lsb-release`
+pub fn parse(file: String) -> ChromeOSLsbRelease {
+    let mut fields = utils::parse_key_values(&file);
+
+    ChromeOSLsbRelease {
+        version: fields.remove("CHROMEOS_RELEASE_VERSION"),
+        milestone: fields.remove("CHROMEOS_RELEASE_CHROME_MILESTONE"),
+        board: fields.remove("CHROMEOS_RELEASE_BOARD"),
+        track: fields.remove("CHROMEOS_RELEASE_TRACK")
+    }
+}
+
+/// Returns `true` inside a Crostini container. The host exposes its
+/// milestone in `/dev/.cros_milestone` and mounts the cros-guest-tools
+/// into `/opt/google/cros-containers`.
+pub fn is_crostini() -> bool {
+    utils::file_exists("/dev/.cros_milestone") || utils::file_exists("/opt/google/cros-containers")
+}
+
+pub fn retrieve_crostini_milestone() -> Option<u32> {
+    match utils::read_file("/dev/.cros_milestone") {
+        Ok(file) => parse_milestone(&file),
+        Err(_) => None
+    }
+}
+
+pub fn parse_milestone(milestone: &str) -> Option<u32> {
+    milestone.trim().parse().ok()
+}
@@ -0,0 +1,28 @@
+/*
+ * Chroot related checks
+ */
+use std::path::Path;
+
+/// Returns `true` if the process runs in a chroot. `None` if that could
+/// not be determined, usually because `/proc/1/root` is only accessible to
+/// root.
+pub fn retrieve() -> Option<bool> {
+    detect(Path::new("/"), Path::new("/proc/1/root"))
+}
+
+/// Compares the device and inode of `root` with those of `init_root`, the
+/// root directory of PID 1. They differ inside a chroot.
+#[cfg(unix)]
+pub fn detect(root: &Path, init_root: &Path) -> Option<bool> {
+    use std::fs;
+    use std::os::unix::fs::MetadataExt;
+
+    let root = fs::metadata(root).ok()?;
+    let init_root = fs::metadata(init_root).ok()?;
+    Some((root.dev(), root.ino()) != (init_root.dev(), init_root.ino()))
+}
+
+#[cfg(not(unix))]
+pub fn detect(_root: &Path, _init_root: &Path) -> Option<bool> {
+    None
+}
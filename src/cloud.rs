@@ -0,0 +1,133 @@
+/*
+ * Cloud provider related checks. These only look at local files and
+ * never query an instance metadata service.
+ */
+use json;
+use std::path::Path;
+use utils;
+
+/// The cloud provider an instance runs at
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CloudProvider {
+    #[cfg_attr(feature = "serde", serde(rename = "aws"))]
+    AWS,
+    #[cfg_attr(feature = "serde", serde(rename = "gce"))]
+    GCE,
+    Azure,
+    #[cfg_attr(feature = "serde", serde(rename = "oracle"))]
+    OracleCloud,
+    DigitalOcean,
+    Hetzner,
+    Alibaba,
+}
+
+/// Holds information about the cloud instance the system runs on
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CloudInfo {
+    pub provider: CloudProvider,
+    /// `true` on AWS instances built on the Nitro system rather than Xen
+    pub nitro: bool,
+    /// The region, if cloud-init recorded it
+    pub region: Option<String>,
+}
+
+pub struct InstanceData {
+    pub cloud_name: Option<String>,
+    pub region: Option<String>
+}
+
+// Azure sets this asset tag on all of its virtual machines
+const AZURE_ASSET_TAG: &str = "7783-7084-3265-9085-8269-3286-77";
+
+pub fn retrieve() -> Option<CloudInfo> {
+    detect(Path::new("/"))
+}
+
+/// Identifies the cloud provider from the DMI strings,
+/// `/sys/hypervisor/uuid` and cloud-init's instance data below `root`
+pub fn detect(root: &Path) -> Option<CloudInfo> {
+    let read = |path: &str| {
+        utils::read_file(utils::rooted(root, path))
+            .map(|value| value.trim().to_owned())
+            .unwrap_or_default()
+    };
+    let sys_vendor = read("/sys/class/dmi/id/sys_vendor");
+    let product_name = read("/sys/class/dmi/id/product_name");
+    let bios_version = read("/sys/class/dmi/id/bios_version");
+    let chassis_asset_tag = read("/sys/class/dmi/id/chassis_asset_tag");
+    let hypervisor_uuid = read("/sys/hypervisor/uuid");
+    let instance_data = utils::read_file(utils::rooted(root, "/run/cloud-init/instance-data.json"))
+        .ok()
+        .map(|file| parse_instance_data(&file));
+
+    let mut nitro = false;
+    let provider = if sys_vendor == "Amazon EC2" {
+        nitro = true;
+        Some(CloudProvider::AWS)
+    } else if bios_version.ends_with(".amazon") || hypervisor_uuid.to_lowercase().starts_with("ec2") {
+        Some(CloudProvider::AWS)
+    } else if sys_vendor == "Google" || product_name == "Google Compute Engine" {
+        Some(CloudProvider::GCE)
+    } else if chassis_asset_tag == AZURE_ASSET_TAG {
+        Some(CloudProvider::Azure)
+    } else if chassis_asset_tag == "OracleCloud.com" {
+        Some(CloudProvider::OracleCloud)
+    } else if sys_vendor == "DigitalOcean" {
+        Some(CloudProvider::DigitalOcean)
+    } else if sys_vendor == "Hetzner" {
+        Some(CloudProvider::Hetzner)
+    } else if sys_vendor.starts_with("Alibaba Cloud") || product_name.starts_with("Alibaba Cloud") {
+        Some(CloudProvider::Alibaba)
+    } else {
+        None
+    };
+
+    let provider = provider.or_else(|| {
+        instance_data.as_ref()
+            .and_then(|data| data.cloud_name.as_ref())
+            .and_then(|cloud_name| parse_cloud_name(cloud_name))
+    })?;
+
+    Some(CloudInfo {
+        provider,
+        nitro,
+        region: instance_data.and_then(|data| data.region),
+    })
+}
+
+/// Extracts the provider and region from the top level `v1` keys of
+/// cloud-init's `/run/cloud-init/instance-data.json`. The datasource
+/// metadata next to them may use the same key names.
+pub fn parse_instance_data(file: &str) -> InstanceData {
+    let instance_data = json::parse(file);
+    let v1 = instance_data.as_ref().and_then(|data| data.get("v1"));
+    let value = |names: &[&str]| {
+        names.iter()
+            .filter_map(|name| v1.and_then(|v1| v1.get(name)).and_then(|value| value.as_str()))
+            .find(|value| !value.is_empty())
+            .map(String::from)
+    };
+
+    InstanceData {
+        cloud_name: value(&["cloud_name", "cloud-name"]),
+        region: value(&["region"])
+    }
+}
+
+/// Maps the cloud names used by cloud-init to a provider
+pub fn parse_cloud_name(cloud_name: &str) -> Option<CloudProvider> {
+    match cloud_name {
+        "aws" => Some(CloudProvider::AWS),
+        "gce" => Some(CloudProvider::GCE),
+        "azure" => Some(CloudProvider::Azure),
+        "oracle" => Some(CloudProvider::OracleCloud),
+        "digitalocean" => Some(CloudProvider::DigitalOcean),
+        "hetzner" => Some(CloudProvider::Hetzner),
+        "aliyun" => Some(CloudProvider::Alibaba),
+        _ => None
+    }
+}
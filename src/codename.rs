@@ -0,0 +1,222 @@
+/*
+ * Bundled release codenames
+ */
+
+use utils;
+
+const MACOS: &[(&str, &str)] = &[
+    ("10.0", "Cheetah"),
+    ("10.1", "Puma"),
+    ("10.2", "Jaguar"),
+    ("10.3", "Panther"),
+    ("10.4", "Tiger"),
+    ("10.5", "Leopard"),
+    ("10.6", "Snow Leopard"),
+    ("10.7", "Lion"),
+    ("10.8", "Mountain Lion"),
+    ("10.9", "Mavericks"),
+    ("10.10", "Yosemite"),
+    ("10.11", "El Capitan"),
+    ("10.12", "Sierra"),
+    ("10.13", "High Sierra"),
+    ("10.14", "Mojave"),
+    ("10.15", "Catalina"),
+    ("11", "Big Sur"),
+    ("12", "Monterey"),
+    ("13", "Ventura"),
+    ("14", "Sonoma"),
+    ("15", "Sequoia"),
+    ("26", "Tahoe"),
+];
+
+const DEBIAN: &[(&str, &str)] = &[
+    ("1.1", "buzz"),
+    ("1.2", "rex"),
+    ("1.3", "bo"),
+    ("2.0", "hamm"),
+    ("2.1", "slink"),
+    ("2.2", "potato"),
+    ("3.0", "woody"),
+    ("3.1", "sarge"),
+    ("4", "etch"),
+    ("5", "lenny"),
+    ("6", "squeeze"),
+    ("7", "wheezy"),
+    ("8", "jessie"),
+    ("9", "stretch"),
+    ("10", "buster"),
+    ("11", "bullseye"),
+    ("12", "bookworm"),
+    ("13", "trixie"),
+    ("14", "forky"),
+    ("15", "duke"),
+];
+
+const UBUNTU: &[(&str, &str)] = &[
+    ("4.10", "warty"),
+    ("5.04", "hoary"),
+    ("5.10", "breezy"),
+    ("6.06", "dapper"),
+    ("6.10", "edgy"),
+    ("7.04", "feisty"),
+    ("7.10", "gutsy"),
+    ("8.04", "hardy"),
+    ("8.10", "intrepid"),
+    ("9.04", "jaunty"),
+    ("9.10", "karmic"),
+    ("10.04", "lucid"),
+    ("10.10", "maverick"),
+    ("11.04", "natty"),
+    ("11.10", "oneiric"),
+    ("12.04", "precise"),
+    ("12.10", "quantal"),
+    ("13.04", "raring"),
+    ("13.10", "saucy"),
+    ("14.04", "trusty"),
+    ("14.10", "utopic"),
+    ("15.04", "vivid"),
+    ("15.10", "wily"),
+    ("16.04", "xenial"),
+    ("16.10", "yakkety"),
+    ("17.04", "zesty"),
+    ("17.10", "artful"),
+    ("18.04", "bionic"),
+    ("18.10", "cosmic"),
+    ("19.04", "disco"),
+    ("19.10", "eoan"),
+    ("20.04", "focal"),
+    ("20.10", "groovy"),
+    ("21.04", "hirsute"),
+    ("21.10", "impish"),
+    ("22.04", "jammy"),
+    ("22.10", "kinetic"),
+    ("23.04", "lunar"),
+    ("23.10", "mantic"),
+    ("24.04", "noble"),
+    ("24.10", "oracular"),
+    ("25.04", "plucky"),
+    ("25.10", "questing"),
+    ("26.04", "resolute"),
+];
+
+const RHEL: &[(&str, &str)] = &[
+    ("5", "Tikanga"),
+    ("6", "Santiago"),
+    ("7", "Maipo"),
+    ("8", "Ootpa"),
+    ("9", "Plow"),
+    ("10", "Coughlan"),
+];
+
+const DEEPIN: &[(&str, &str)] = &[
+    ("20", "apricot"),
+    ("23", "beige"),
+];
+
+const ANDROID: &[(&str, &str)] = &[
+    ("4.0", "Ice Cream Sandwich"),
+    ("4.1", "Jelly Bean"),
+    ("4.2", "Jelly Bean"),
+    ("4.3", "Jelly Bean"),
+    ("4.4", "KitKat"),
+    ("5", "Lollipop"),
+    ("6", "Marshmallow"),
+    ("7", "Nougat"),
+    ("8", "Oreo"),
+    ("9", "Pie"),
+    ("10", "Quince Tart"),
+    ("11", "Red Velvet Cake"),
+    ("12", "Snow Cone"),
+    ("13", "Tiramisu"),
+    ("14", "Upside Down Cake"),
+    ("15", "Vanilla Ice Cream"),
+    ("16", "Baklava"),
+];
+
+/// Returns the codename table of a distribution. Distributions without
+/// codenames, like Fedora or Alpine, have none.
+fn table(distro: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    match distro {
+        "macos" => Some(MACOS),
+        "debian" => Some(DEBIAN),
+        "ubuntu" => Some(UBUNTU),
+        "rhel" => Some(RHEL),
+        "deepin" => Some(DEEPIN),
+        "android" => Some(ANDROID),
+        _ => None
+    }
+}
+
+/// Returns the marketing name Apple used for the given macOS product
+/// version, e.g. `Big Sur` for `11.7.10`.
+pub fn macos(version: &str) -> Option<&'static str> {
+    lookup("macos", version)
+}
+
+/// Returns the codename of a release, e.g. `jammy` for Ubuntu `22.04.3`.
+/// `distro` is the os-release style ID, e.g. `debian` or `rhel`.
+pub fn lookup(distro: &str, version: &str) -> Option<&'static str> {
+    table(distro)?.iter()
+        .find(|&&(release, _)| utils::is_release(version.trim(), release))
+        .map(|&(_, codename)| codename)
+}
+
+/// Returns the release carrying a codename, ignoring case, e.g. `12` for
+/// Debian `bookworm`. For codenames shared by several releases the first
+/// one is returned.
+pub fn version(distro: &str, codename: &str) -> Option<&'static str> {
+    table(distro)?.iter()
+        .find(|&&(_, name)| name.eq_ignore_ascii_case(codename.trim()))
+        .map(|&(release, _)| release)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn macos_release_names() {
+        assert_eq!(macos("10.15.7"), Some("Catalina"));
+        assert_eq!(macos("11.7.10"), Some("Big Sur"));
+        assert_eq!(macos("12.6"), Some("Monterey"));
+        assert_eq!(macos("13.6.1"), Some("Ventura"));
+        assert_eq!(macos("14.4.1"), Some("Sonoma"));
+        assert_eq!(macos("15.0"), Some("Sequoia"));
+    }
+
+    #[test]
+    fn unknown_macos_release_name() {
+        assert_eq!(macos("9.2"), None);
+        assert_eq!(macos("0.0.0"), None);
+    }
+
+    #[test]
+    fn linux_codenames() {
+        assert_eq!(lookup("debian", "11"), Some("bullseye"));
+        assert_eq!(lookup("debian", "12.5"), Some("bookworm"));
+        assert_eq!(lookup("debian", "13"), Some("trixie"));
+        assert_eq!(lookup("ubuntu", "20.04"), Some("focal"));
+        assert_eq!(lookup("ubuntu", "22.04.3"), Some("jammy"));
+        assert_eq!(lookup("ubuntu", "24.04"), Some("noble"));
+        assert_eq!(lookup("rhel", "8.9"), Some("Ootpa"));
+        assert_eq!(lookup("android", "14"), Some("Upside Down Cake"));
+    }
+
+    #[test]
+    fn distros_without_codenames() {
+        assert_eq!(lookup("fedora", "39"), None);
+        assert_eq!(lookup("alpine", "3.19.1"), None);
+        assert_eq!(lookup("debian", "0.0.0"), None);
+        assert_eq!(lookup("ubuntu", "22.0"), None);
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        assert_eq!(version("debian", "bookworm"), Some("12"));
+        assert_eq!(version("ubuntu", "Jammy"), Some("22.04"));
+        assert_eq!(version("macos", "sonoma"), Some("14"));
+        assert_eq!(version("android", "Jelly Bean"), Some("4.1"));
+        assert_eq!(version("ubuntu", "bookworm"), None);
+        assert_eq!(version("fedora", "rawhide"), None);
+    }
+}
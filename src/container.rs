@@ -0,0 +1,243 @@
+/*
+ * Container related checks
+ */
+use std::env;
+use std::path::Path;
+use utils;
+
+/// The container runtime a process runs in
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ContainerRuntime {
+    Docker,
+    Podman,
+    #[cfg_attr(feature = "serde", serde(rename = "lxc"))]
+    LXC,
+    SystemdNspawn,
+    Containerd,
+    #[cfg_attr(feature = "serde", serde(rename = "cri-o"))]
+    CRIO,
+    /// Inside a container, but the runtime could not be determined
+    Unknown,
+    /// A runtime that announced itself by a name not listed above
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Other(String),
+}
+
+/// The cgroup hierarchy in use
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CGroupVersion {
+    V1,
+    V2,
+}
+
+/// A tool running interactive containers integrated with the host
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ContainerTool {
+    Toolbox,
+    Distrobox,
+}
+
+/// The contents of `/run/.containerenv`, written by Podman and other
+/// containers/libpod based tools
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContainerEnv {
+    /// The engine and its version, e.g. `podman-4.6.1`
+    pub engine: Option<String>,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub image: Option<String>,
+    pub rootless: bool,
+}
+
+/// Holds information about the container the process runs in
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContainerInfo {
+    pub runtime: ContainerRuntime,
+    /// `true` if the container is part of a Kubernetes pod
+    pub kubernetes: bool,
+    pub cgroup_version: Option<CGroupVersion>,
+    pub containerenv: Option<ContainerEnv>,
+    /// Set inside Toolbox and Distrobox containers, which mount the host
+    /// file system at `/run/host`
+    pub tool: Option<ContainerTool>,
+}
+
+// Distrobox bind mounts its helpers into every container it creates
+const DISTROBOX_MARKERS: [&str; 2] = ["/usr/bin/distrobox-host-exec", "/usr/bin/distrobox-export"];
+
+pub fn retrieve() -> Option<ContainerInfo> {
+    let kubernetes_env = env::var_os("KUBERNETES_SERVICE_HOST").is_some();
+
+    match detect(Path::new("/")) {
+        Some(mut info) => {
+            info.kubernetes = info.kubernetes || kubernetes_env;
+            Some(info)
+        }
+        None if kubernetes_env => Some(ContainerInfo {
+            runtime: ContainerRuntime::Unknown,
+            kubernetes: true,
+            cgroup_version: None,
+            containerenv: None,
+            tool: None,
+        }),
+        None => None
+    }
+}
+
+/// Looks for container markers below `root`. Returns `None` if none
+/// were found.
+pub fn detect(root: &Path) -> Option<ContainerInfo> {
+    let cgroup = utils::read_file(utils::rooted(root, "/proc/self/cgroup")).unwrap_or_default();
+    let containerenv = utils::read_file(utils::rooted(root, "/run/.containerenv"))
+        .ok()
+        .map(|file| parse_containerenv(&file));
+
+    let runtime = if let Some(ref containerenv) = containerenv {
+        Some(containerenv_runtime(containerenv, &cgroup))
+    } else if utils::file_exists(utils::rooted(root, "/.dockerenv")) {
+        Some(ContainerRuntime::Docker)
+    } else {
+        utils::read_file(utils::rooted(root, "/run/systemd/container"))
+            .ok()
+            .and_then(|name| parse_runtime_name(&name))
+            .or_else(|| {
+                utils::read_file(utils::rooted(root, "/proc/1/environ"))
+                    .ok()
+                    .and_then(|environ| parse_environ(&environ))
+            })
+            .or_else(|| parse_cgroup_runtime(&cgroup))
+    };
+
+    let kubernetes = is_kubepods(&cgroup)
+        || utils::file_exists(utils::rooted(root, "/var/run/secrets/kubernetes.io/serviceaccount"))
+        || utils::file_exists(utils::rooted(root, "/run/secrets/kubernetes.io/serviceaccount"));
+
+    let runtime = match runtime {
+        Some(runtime) => runtime,
+        None if kubernetes => ContainerRuntime::Unknown,
+        None => return None
+    };
+
+    let tool = detect_tool(root);
+
+    Some(ContainerInfo {
+        runtime,
+        kubernetes,
+        cgroup_version: parse_cgroup_version(&cgroup),
+        containerenv,
+        tool,
+    })
+}
+
+/// Podman and CRI-O both write `/run/.containerenv`. Podman names itself
+/// in `engine` for privileged containers, otherwise the cgroup path tells
+/// them apart.
+fn containerenv_runtime(containerenv: &ContainerEnv, cgroup: &str) -> ContainerRuntime {
+    match containerenv.engine.as_deref() {
+        Some(engine) if engine.starts_with("podman") => ContainerRuntime::Podman,
+        Some(engine) if engine.starts_with("cri-o") || engine.starts_with("crio") => ContainerRuntime::CRIO,
+        _ if parse_cgroup_runtime(cgroup) == Some(ContainerRuntime::CRIO) => ContainerRuntime::CRIO,
+        _ => ContainerRuntime::Podman,
+    }
+}
+
+/// Tells Toolbox and Distrobox containers apart by the files they place
+/// in every container: `/run/.toolboxenv` for Toolbox and the helpers for
+/// Distrobox. Names and `/run/host` are not used, as any container may
+/// have them.
+fn detect_tool(root: &Path) -> Option<ContainerTool> {
+    if utils::file_exists(utils::rooted(root, "/run/.toolboxenv")) {
+        Some(ContainerTool::Toolbox)
+    } else if DISTROBOX_MARKERS.iter().any(|path| utils::file_exists(utils::rooted(root, path))) {
+        Some(ContainerTool::Distrobox)
+    } else {
+        None
+    }
+}
+
+/// Parses the `key="value"` body of `/run/.containerenv`. Podman only
+/// fills it in for privileged containers, so it may be empty.
+pub fn parse_containerenv(file: &str) -> ContainerEnv {
+    let mut fields = utils::parse_key_values(file);
+
+    ContainerEnv {
+        engine: fields.remove("engine"),
+        name: fields.remove("name"),
+        id: fields.remove("id"),
+        image: fields.remove("image"),
+        rootless: fields.get("rootless").map(String::as_str) == Some("1"),
+    }
+}
+
+/// Maps the value of the `container` variable systemd expects from
+/// container managers, e.g. `systemd-nspawn` or `lxc`
+pub fn parse_runtime_name(name: &str) -> Option<ContainerRuntime> {
+    let runtime = match name.trim() {
+        "" => return None,
+        "docker" => ContainerRuntime::Docker,
+        "podman" => ContainerRuntime::Podman,
+        "lxc" | "lxc-libvirt" => ContainerRuntime::LXC,
+        "systemd-nspawn" => ContainerRuntime::SystemdNspawn,
+        other => ContainerRuntime::Other(other.to_owned()),
+    };
+    Some(runtime)
+}
+
+/// Reads the `container` variable from the NUL separated environment of
+/// PID 1, as found in `/proc/1/environ`
+pub fn parse_environ(environ: &str) -> Option<ContainerRuntime> {
+    environ.split('\0')
+        .find(|variable| variable.starts_with("container="))
+        .and_then(|variable| parse_runtime_name(&variable["container=".len()..]))
+}
+
+/// Returns the cgroup version in use according to `/proc/self/cgroup`.
+/// cgroup v2 has a single `0::` hierarchy, v1 numbers one per controller.
+pub fn parse_cgroup_version(cgroup: &str) -> Option<CGroupVersion> {
+    let mut hierarchies = cgroup.lines().filter(|line| !line.trim().is_empty()).peekable();
+    hierarchies.peek()?;
+
+    if hierarchies.all(|line| line.starts_with("0::")) {
+        Some(CGroupVersion::V2)
+    } else {
+        Some(CGroupVersion::V1)
+    }
+}
+
+/// Identifies the runtime from the cgroup paths it creates, such as
+/// `/docker/<id>` on cgroup v1 or `docker-<id>.scope` on cgroup v2
+pub fn parse_cgroup_runtime(cgroup: &str) -> Option<ContainerRuntime> {
+    for line in cgroup.lines() {
+        let path = match line.splitn(3, ':').nth(2) {
+            Some(path) => path,
+            None => continue
+        };
+
+        if path.contains("/libpod-") || path.contains("/libpod_parent") {
+            return Some(ContainerRuntime::Podman);
+        } else if path.contains("/docker/") || path.contains("/docker-") {
+            return Some(ContainerRuntime::Docker);
+        } else if path.contains("/cri-containerd-") || path.contains("/containerd/") {
+            return Some(ContainerRuntime::Containerd);
+        } else if path.contains("/crio-") {
+            return Some(ContainerRuntime::CRIO);
+        } else if path.contains("/lxc/") || path.contains("/lxc.payload") {
+            return Some(ContainerRuntime::LXC);
+        }
+    }
+    None
+}
+
+/// Returns `true` if the cgroup paths belong to a Kubernetes pod
+pub fn is_kubepods(cgroup: &str) -> bool {
+    cgroup.contains("/kubepods")
+}
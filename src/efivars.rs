@@ -0,0 +1,40 @@
+/*
+ * EFI variable access through efivarfs
+ */
+use std::fs;
+use std::path::Path;
+use utils;
+
+/// The vendor GUID of the variables defined by the UEFI specification
+pub const GLOBAL_GUID: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+/// The vendor GUID of the Boot Loader Interface variables, set by
+/// systemd-boot and other boot loaders
+pub const LOADER_GUID: &str = "4a67b082-0a4c-41cf-b6c7-440b29bb8c4f";
+
+/// Returns the data of an EFI variable, without the attribute bytes
+/// efivarfs prefixes it with
+pub fn read(root: &Path, name: &str, guid: &str) -> Option<Vec<u8>> {
+    let path = utils::rooted(root, "/sys/firmware/efi/efivars").join(format!("{}-{}", name, guid));
+    let contents = fs::read(path).ok()?;
+    if contents.len() < 4 {
+        return None;
+    }
+    Some(contents[4..].to_vec())
+}
+
+/// Reads a one byte boolean variable like `SecureBoot`
+pub fn read_bool(root: &Path, name: &str, guid: &str) -> Option<bool> {
+    read(root, name, guid)?.first().map(|&value| value == 1)
+}
+
+/// Reads a NUL terminated UTF-16 string variable like `LoaderInfo`
+pub fn read_string(root: &Path, name: &str, guid: &str) -> Option<String> {
+    let data = read(root, name, guid)?;
+    let units: Vec<u16> = data.chunks(2)
+        .filter(|chunk| chunk.len() == 2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+    String::from_utf16(&units).ok()
+}
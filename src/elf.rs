@@ -0,0 +1,204 @@
+/*
+ * Minimal ELF header parsing, used to learn about the userland without
+ * linking against it
+ */
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+pub const EM_386: u16 = 3;
+pub const EM_MIPS: u16 = 8;
+pub const EM_PPC: u16 = 20;
+pub const EM_PPC64: u16 = 21;
+pub const EM_S390: u16 = 22;
+pub const EM_ARM: u16 = 40;
+pub const EM_X86_64: u16 = 62;
+pub const EM_AARCH64: u16 = 183;
+pub const EM_RISCV: u16 = 243;
+pub const EM_LOONGARCH: u16 = 258;
+
+const PT_INTERP: u32 = 3;
+
+// The program headers and the interpreter path follow the ELF header
+// closely, so the start of the file is enough to find them
+const INTERPRETER_READ_LIMIT: u64 = 4096;
+
+pub struct ElfHeader {
+    /// `32` or `64`
+    pub bits: u32,
+    pub little_endian: bool,
+    pub machine: u16
+}
+
+pub fn read_header<P: AsRef<Path>>(path: P) -> Option<ElfHeader> {
+    let mut bytes = Vec::new();
+    File::open(path).ok()?.take(64).read_to_end(&mut bytes).ok()?;
+    parse_header(&bytes)
+}
+
+pub fn parse_header(bytes: &[u8]) -> Option<ElfHeader> {
+    if bytes.len() < 52 || &bytes[0..4] != b"\x7fELF" {
+        return None;
+    }
+
+    let bits = match bytes[4] {
+        1 => 32,
+        2 => 64,
+        _ => return None
+    };
+    let little_endian = match bytes[5] {
+        1 => true,
+        2 => false,
+        _ => return None
+    };
+
+    Some(ElfHeader {
+        bits,
+        little_endian,
+        machine: read_u16(&bytes[18..20], little_endian)
+    })
+}
+
+pub fn read_u16(bytes: &[u8], little_endian: bool) -> u16 {
+    let bytes = [bytes[0], bytes[1]];
+    if little_endian {
+        u16::from_le_bytes(bytes)
+    } else {
+        u16::from_be_bytes(bytes)
+    }
+}
+
+pub fn read_u32(bytes: &[u8], little_endian: bool) -> u32 {
+    let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    }
+}
+
+pub fn read_u64(bytes: &[u8], little_endian: bool) -> u64 {
+    let mut word = [0; 8];
+    word.copy_from_slice(&bytes[..8]);
+    if little_endian {
+        u64::from_le_bytes(word)
+    } else {
+        u64::from_be_bytes(word)
+    }
+}
+
+pub fn read_interpreter<P: AsRef<Path>>(path: P) -> Option<String> {
+    let mut bytes = Vec::new();
+    File::open(path).ok()?.take(INTERPRETER_READ_LIMIT).read_to_end(&mut bytes).ok()?;
+    parse_interpreter(&bytes)
+}
+
+/// Reads the contents of a section, e.g. `.rodata`, from the section
+/// headers, without reading the rest of the file
+pub fn read_section<P: AsRef<Path>>(path: P, name: &str) -> Option<Vec<u8>> {
+    let mut file = File::open(path).ok()?;
+    let bytes = read_at(&mut file, 0, 64)?;
+    let header = parse_header(&bytes)?;
+    let le = header.little_endian;
+    let (shoff, shentsize, shnum, shstrndx) = if header.bits == 64 {
+        (
+            read_u64(&bytes[40..48], le),
+            read_u16(&bytes[58..60], le) as usize,
+            read_u16(&bytes[60..62], le) as usize,
+            read_u16(&bytes[62..64], le) as usize,
+        )
+    } else {
+        (
+            read_u32(&bytes[32..36], le) as u64,
+            read_u16(&bytes[46..48], le) as usize,
+            read_u16(&bytes[48..50], le) as usize,
+            read_u16(&bytes[50..52], le) as usize,
+        )
+    };
+
+    let table = read_at(&mut file, shoff, shnum.checked_mul(shentsize)? as u64)?;
+    // The name index, offset and size of a section header
+    let section = |index: usize| -> Option<(usize, u64, u64)> {
+        let start = index.checked_mul(shentsize)?;
+        let entry = table.get(start..start.checked_add(shentsize)?)?;
+        if header.bits == 64 {
+            Some((
+                read_u32(entry.get(0..4)?, le) as usize,
+                read_u64(entry.get(24..32)?, le),
+                read_u64(entry.get(32..40)?, le),
+            ))
+        } else {
+            Some((
+                read_u32(entry.get(0..4)?, le) as usize,
+                read_u32(entry.get(16..20)?, le) as u64,
+                read_u32(entry.get(20..24)?, le) as u64,
+            ))
+        }
+    };
+
+    let (_, names_offset, names_size) = section(shstrndx)?;
+    let names = read_at(&mut file, names_offset, names_size)?;
+    let (_, offset, size) = (0..shnum)
+        .filter_map(section)
+        .find(|&(name_index, _, _)| {
+            names.get(name_index..)
+                .and_then(|names| names.split(|&byte| byte == 0).next())
+                .is_some_and(|section_name| section_name == name.as_bytes())
+        })?;
+    read_at(&mut file, offset, size)
+}
+
+fn read_at(file: &mut File, offset: u64, len: u64) -> Option<Vec<u8>> {
+    file.seek(SeekFrom::Start(offset)).ok()?;
+    let mut bytes = Vec::new();
+    file.by_ref().take(len).read_to_end(&mut bytes).ok()?;
+    if bytes.len() as u64 == len {
+        Some(bytes)
+    } else {
+        None
+    }
+}
+
+/// Returns the program interpreter, i.e. the dynamic loader, requested by
+/// the `PT_INTERP` program header, e.g. `/lib64/ld-linux-x86-64.so.2`
+pub fn parse_interpreter(bytes: &[u8]) -> Option<String> {
+    let header = parse_header(bytes)?;
+    let le = header.little_endian;
+    let (phoff, phentsize, phnum) = if header.bits == 64 {
+        (
+            read_u64(bytes.get(32..40)?, le) as usize,
+            read_u16(bytes.get(54..56)?, le) as usize,
+            read_u16(bytes.get(56..58)?, le) as usize,
+        )
+    } else {
+        (
+            read_u32(bytes.get(28..32)?, le) as usize,
+            read_u16(bytes.get(42..44)?, le) as usize,
+            read_u16(bytes.get(44..46)?, le) as usize,
+        )
+    };
+
+    for index in 0..phnum {
+        let start = phoff.checked_add(index.checked_mul(phentsize)?)?;
+        let program_header = bytes.get(start..start.checked_add(phentsize)?)?;
+        if program_header.len() < 4 || read_u32(program_header, le) != PT_INTERP {
+            continue;
+        }
+
+        let (offset, size) = if header.bits == 64 {
+            (
+                read_u64(program_header.get(8..16)?, le) as usize,
+                read_u64(program_header.get(32..40)?, le) as usize,
+            )
+        } else {
+            (
+                read_u32(program_header.get(4..8)?, le) as usize,
+                read_u32(program_header.get(16..20)?, le) as usize,
+            )
+        };
+        let interpreter = bytes.get(offset..offset.checked_add(size)?)?;
+        let interpreter = interpreter.split(|&byte| byte == 0).next()?;
+        return String::from_utf8(interpreter.to_vec()).ok();
+    }
+    None
+}
@@ -0,0 +1,138 @@
+/*
+ * Firmware and boot related checks
+ */
+use efivars;
+use std::path::Path;
+use utils;
+
+/// The firmware interface the system booted through
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum BootMode {
+    #[cfg_attr(feature = "serde", serde(rename = "uefi"))]
+    UEFI,
+    /// Legacy BIOS, or UEFI with a compatibility support module
+    #[cfg_attr(feature = "serde", serde(rename = "bios"))]
+    BIOS,
+    /// The firmware isn't visible, e.g. because a container masks
+    /// `/sys/firmware` or the kernel was booted directly by a hypervisor
+    Unknown,
+}
+
+/// A boot loader
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Bootloader {
+    SystemdBoot,
+    Grub,
+    /// A boot loader that announced itself by a name not listed above
+    #[cfg_attr(feature = "serde", serde(untagged))]
+    Other(String),
+}
+
+/// Holds information about the firmware and the boot process
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FirmwareInfo {
+    pub boot_mode: BootMode,
+    /// The bitness of the UEFI firmware, 32 or 64
+    pub platform_bits: Option<u32>,
+    /// `true` for 32-bit UEFI on a 64-bit capable CPU, which needs a
+    /// 32-bit EFI boot loader
+    pub mixed_mode: bool,
+    pub secure_boot: Option<bool>,
+    /// `true` if no platform key is enrolled, so Secure Boot keys can be
+    /// changed freely
+    pub setup_mode: Option<bool>,
+    pub bootloader: Option<Bootloader>,
+    /// The boot loader version, as reported through the `LoaderInfo`
+    /// EFI variable
+    pub bootloader_version: Option<String>,
+}
+
+const GRUB_CONFIGS: [&str; 3] = ["/boot/grub/grub.cfg", "/boot/grub2/grub.cfg", "/boot/efi/EFI/BOOT/grub.cfg"];
+
+/// Returns the firmware state as seen by the running Linux kernel. Other
+/// systems don't expose it through sysfs, so they return `None`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn retrieve() -> Option<FirmwareInfo> {
+    Some(detect(Path::new("/")))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn retrieve() -> Option<FirmwareInfo> {
+    None
+}
+
+/// Reads the firmware state from sysfs and efivarfs below `root`, and
+/// looks for boot loader configuration in `/boot`
+pub fn detect(root: &Path) -> FirmwareInfo {
+    let boot_mode = detect_boot_mode(root);
+    let platform_bits = utils::read_file(utils::rooted(root, "/sys/firmware/efi/fw_platform_size"))
+        .ok()
+        .and_then(|size| size.trim().parse::<u32>().ok());
+    let cpu_64bit = utils::read_file(utils::rooted(root, "/proc/cpuinfo"))
+        .map(|cpuinfo| has_long_mode(&cpuinfo))
+        .unwrap_or(false);
+
+    let loader_info = efivars::read_string(root, "LoaderInfo", efivars::LOADER_GUID);
+    let (bootloader, bootloader_version) = match loader_info {
+        Some(info) => {
+            let (bootloader, version) = parse_loader_info(&info);
+            (Some(bootloader), version)
+        }
+        None if GRUB_CONFIGS.iter().any(|path| utils::file_exists(utils::rooted(root, path))) => {
+            (Some(Bootloader::Grub), None)
+        }
+        None => (None, None),
+    };
+
+    FirmwareInfo {
+        boot_mode,
+        platform_bits,
+        mixed_mode: platform_bits == Some(32) && cpu_64bit,
+        secure_boot: efivars::read_bool(root, "SecureBoot", efivars::GLOBAL_GUID),
+        setup_mode: efivars::read_bool(root, "SetupMode", efivars::GLOBAL_GUID),
+        bootloader,
+        bootloader_version,
+    }
+}
+
+/// UEFI firmware always shows up as `/sys/firmware/efi`. Its absence only
+/// means BIOS if the firmware is visible at all, which the SMBIOS tables
+/// in `/sys/firmware/dmi` prove. Containers mask `/sys/firmware` or leave
+/// only parts of it, and directly booted VMs have no firmware tables.
+fn detect_boot_mode(root: &Path) -> BootMode {
+    if utils::file_exists(utils::rooted(root, "/sys/firmware/efi")) {
+        BootMode::UEFI
+    } else if utils::file_exists(utils::rooted(root, "/sys/firmware/dmi")) {
+        BootMode::BIOS
+    } else {
+        BootMode::Unknown
+    }
+}
+
+/// Splits the `LoaderInfo` variable, e.g. `systemd-boot 254.5-1-arch` or
+/// `GRUB 2.12`, into the boot loader and its version
+pub fn parse_loader_info(info: &str) -> (Bootloader, Option<String>) {
+    let mut parts = info.trim().splitn(2, ' ');
+    let name = parts.next().unwrap_or_default();
+    let version = parts.next().map(|version| version.trim().to_owned());
+
+    let bootloader = match name.to_lowercase().as_str() {
+        "systemd-boot" => Bootloader::SystemdBoot,
+        "grub" => Bootloader::Grub,
+        _ => Bootloader::Other(name.to_owned()),
+    };
+    (bootloader, version)
+}
+
+/// Returns `true` if the x86 `lm` flag marks the CPU as 64-bit capable
+pub fn has_long_mode(cpuinfo: &str) -> bool {
+    cpuinfo.lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "lm"))
+}
@@ -0,0 +1,69 @@
+/*
+ * illumos and Solaris related checks
+ */
+use regex::Regex;
+use utils;
+
+pub struct IllumosRelease {
+    pub distro: Option<String>,
+    pub version: Option<String>
+}
+
+pub fn retrieve() -> Option<IllumosRelease> {
+    if let Ok(release) = utils::read_file("/etc/os-release") {
+        let release = parse_os_release(release);
+        if release.distro.is_some() {
+            return Some(release);
+        }
+    }
+
+    if let Ok(release) = utils::read_file("/etc/release") {
+        Some(parse(release))
+    } else {
+        None
+    }
+}
+
+/// Parses `/etc/release`, whose first line names the distribution, e.g.
+/// `OmniOS v11 r151046l` or `Oracle Solaris 11.4 X86`
+pub fn parse(file: String) -> IllumosRelease {
+    let patterns = [
+        ("OmniOS", r"OmniOS v\d+ (r\w+)"),
+        ("OpenIndiana", r"OpenIndiana (?:Hipster |Development )?(\S+)"),
+        ("SmartOS", r"SmartOS (\w+)"),
+        ("Solaris", r"Solaris (\d+(?:\.\d+)*)"),
+    ];
+
+    for &(distro, pattern) in patterns.iter() {
+        let regex = Regex::new(pattern).unwrap();
+        if let Some(m) = regex.captures(&file) {
+            return IllumosRelease {
+                distro: Some(distro.to_owned()),
+                version: m.get(1).map(|version| version.as_str().to_owned())
+            };
+        }
+    }
+
+    IllumosRelease {
+        distro: None,
+        version: None
+    }
+}
+
+/// Parses the os-release file shipped by current illumos distributions
+/// and Oracle Solaris 11.4
+pub fn parse_os_release(file: String) -> IllumosRelease {
+    let mut fields = utils::parse_key_values(&file);
+    let distro = match fields.get("ID").map(String::as_str) {
+        Some("omnios") => Some("OmniOS"),
+        Some("openindiana") => Some("OpenIndiana"),
+        Some("smartos") => Some("SmartOS"),
+        Some("solaris") => Some("Solaris"),
+        _ => None
+    };
+
+    IllumosRelease {
+        distro: distro.map(String::from),
+        version: fields.remove("VERSION_ID")
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * Immutable and image based OS related checks
+ */
+use std::fs;
+use std::path::Path;
+use utils;
+
+/// An image based operating system whose root file system is read-only
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ImmutableOS {
+    /// Fedora Silverblue, Kinoite and the other Fedora Atomic desktops
+    FedoraAtomic,
+    #[cfg_attr(feature = "serde", serde(rename = "fedora-coreos"))]
+    FedoraCoreOS,
+    /// Red Hat Enterprise Linux CoreOS, as used by OpenShift
+    #[cfg_attr(feature = "serde", serde(rename = "rhcos"))]
+    RHCOS,
+    Flatcar,
+    Bottlerocket,
+    Talos,
+    /// Any other ostree based system
+    #[cfg_attr(feature = "serde", serde(rename = "ostree"))]
+    OSTree,
+}
+
+/// Holds information about an immutable system
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ImmutableInfo {
+    pub os: ImmutableOS,
+    /// The variant, e.g. `kinoite` or Bottlerocket's `aws-k8s-1.28`
+    pub variant: Option<String>,
+    /// The version of the deployed image, e.g. `39.20240112.3.0`
+    pub image_version: Option<String>,
+    /// The update channel or stream, e.g. `stable`
+    pub channel: Option<String>,
+    /// `true` if booted from an ostree deployment
+    pub ostree: bool,
+}
+
+const FEDORA_ATOMIC_VARIANTS: [&str; 6] = ["silverblue", "kinoite", "sericea", "onyx", "budgie-atomic", "cosmic-atomic"];
+
+pub fn retrieve() -> Option<ImmutableInfo> {
+    detect(Path::new("/"))
+}
+
+/// Identifies immutable systems from the os-release and ostree files below
+/// `root`
+pub fn detect(root: &Path) -> Option<ImmutableInfo> {
+    let mut os_release = utils::read_file(utils::rooted(root, "/etc/os-release"))
+        .or_else(|_| utils::read_file(utils::rooted(root, "/usr/lib/os-release")))
+        .map(|file| utils::parse_key_values(&file))
+        .unwrap_or_default();
+    let ostree = utils::file_exists(utils::rooted(root, "/run/ostree-booted"));
+    let id = os_release.get("ID").cloned().unwrap_or_default();
+    let variant_id = os_release.get("VARIANT_ID").cloned();
+
+    let os = match (id.as_str(), variant_id.as_deref()) {
+        ("fedora", Some("coreos")) => ImmutableOS::FedoraCoreOS,
+        ("fedora", Some(variant)) if FEDORA_ATOMIC_VARIANTS.contains(&variant) => ImmutableOS::FedoraAtomic,
+        ("rhcos", _) => ImmutableOS::RHCOS,
+        ("flatcar", _) => ImmutableOS::Flatcar,
+        ("bottlerocket", _) => ImmutableOS::Bottlerocket,
+        ("talos", _) => ImmutableOS::Talos,
+        _ if ostree => ImmutableOS::OSTree,
+        _ => return None,
+    };
+
+    let channel = match os {
+        ImmutableOS::Flatcar => flatcar_channel(root),
+        ImmutableOS::FedoraCoreOS => ostree_channel(root),
+        _ => None,
+    };
+    let image_version = os_release.remove("OSTREE_VERSION")
+        .or_else(|| os_release.remove("VERSION_ID"));
+
+    Some(ImmutableInfo {
+        os,
+        variant: variant_id,
+        image_version,
+        channel,
+        ostree,
+    })
+}
+
+/// Returns the Flatcar update group, which the local configuration in
+/// `/etc` overrides
+fn flatcar_channel(root: &Path) -> Option<String> {
+    ["/etc/flatcar/update.conf", "/usr/share/flatcar/update.conf"].iter()
+        .filter_map(|path| utils::read_file(utils::rooted(root, path)).ok())
+        .filter_map(|file| utils::parse_key_values(&file).remove("GROUP"))
+        .next()
+}
+
+/// Returns the update stream from the origin of the first ostree
+/// deployment found below `/ostree/deploy`
+fn ostree_channel(root: &Path) -> Option<String> {
+    let mut origins: Vec<_> = fs::read_dir(utils::rooted(root, "/ostree/deploy")).ok()?
+        .filter_map(|stateroot| stateroot.ok())
+        .filter_map(|stateroot| fs::read_dir(stateroot.path().join("deploy")).ok())
+        .flat_map(|deployments| deployments.filter_map(|deployment| deployment.ok()))
+        .map(|deployment| deployment.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "origin"))
+        .collect();
+    origins.sort();
+
+    origins.iter()
+        .filter_map(|origin| utils::read_file(origin).ok())
+        .filter_map(|origin| parse_origin(&origin))
+        .next()
+}
+
+/// Extracts the stream from an ostree origin file, e.g. `stable` from
+/// `refspec=fedora:fedora/x86_64/coreos/stable` or from the tag of a
+/// `container-image-reference`
+pub fn parse_origin(origin: &str) -> Option<String> {
+    let fields = utils::parse_key_values(origin);
+    let reference = fields.get("refspec").or_else(|| fields.get("container-image-reference"))?;
+    reference.rsplit(['/', ':'])
+        .next()
+        .filter(|channel| !channel.is_empty())
+        .map(String::from)
+}
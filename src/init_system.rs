@@ -0,0 +1,112 @@
+/*
+ * Init system related checks
+ */
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use utils;
+
+/// The init system and service manager
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum InitSystem {
+    Systemd,
+    #[cfg_attr(feature = "serde", serde(rename = "openrc"))]
+    OpenRC,
+    Runit,
+    S6,
+    #[cfg_attr(feature = "serde", serde(rename = "sysvinit"))]
+    SysVinit,
+    Launchd,
+    /// PID 1 is an application or a minimal reaper like `tini`, as is
+    /// common in containers, so there is no service manager
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
+    NoInit,
+    /// The init system could not be determined
+    Unknown,
+}
+
+/// Holds information about the init system
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InitInfo {
+    pub init: InitSystem,
+    /// The version, currently only known for systemd, e.g. `252`
+    pub version: Option<String>,
+    /// The name of PID 1, e.g. `systemd` or `tini`
+    pub pid1: Option<String>,
+}
+
+const SYSTEMD_DIRS: [&str; 6] = [
+    "/usr/lib/systemd",
+    "/lib/systemd",
+    "/usr/lib64/systemd",
+    "/usr/lib/x86_64-linux-gnu/systemd",
+    "/usr/lib/aarch64-linux-gnu/systemd",
+    "/usr/lib/arm-linux-gnueabihf/systemd",
+];
+
+pub fn retrieve() -> InitInfo {
+    detect(Path::new("/"))
+}
+
+/// Identifies the init system below `root` from PID 1 and the runtime
+/// directories the init systems create
+pub fn detect(root: &Path) -> InitInfo {
+    let pid1 = utils::read_file(utils::rooted(root, "/proc/1/comm"))
+        .ok()
+        .map(|comm| comm.trim().to_owned())
+        .filter(|comm| !comm.is_empty())
+        .or_else(|| {
+            fs::read_link(utils::rooted(root, "/proc/1/exe"))
+                .ok()
+                .and_then(|exe| exe.file_name().map(|name| name.to_string_lossy().into_owned()))
+        });
+    let pid1_name = pid1.as_ref().map_or("", String::as_str);
+    let exists = |path: &str| utils::file_exists(utils::rooted(root, path));
+
+    let init = if pid1_name == "systemd" || exists("/run/systemd/system") {
+        InitSystem::Systemd
+    } else if pid1_name == "openrc-init" || exists("/run/openrc") {
+        InitSystem::OpenRC
+    } else if pid1_name == "runit" || exists("/run/runit") {
+        InitSystem::Runit
+    } else if pid1_name.starts_with("s6-svscan") || exists("/run/s6") {
+        InitSystem::S6
+    } else if pid1_name == "launchd" || exists("/sbin/launchd") {
+        InitSystem::Launchd
+    } else if pid1_name == "init" {
+        InitSystem::SysVinit
+    } else if pid1.is_some() {
+        InitSystem::NoInit
+    } else {
+        InitSystem::Unknown
+    };
+
+    let version = if init == InitSystem::Systemd {
+        systemd_version(root)
+    } else {
+        None
+    };
+
+    InitInfo { init, version, pid1 }
+}
+
+/// Extracts the systemd version from the name of its shared library,
+/// e.g. `libsystemd-shared-252.so` or `libsystemd-shared-255.4-1.fc40.so`
+pub fn parse_systemd_shared_version(name: &str) -> Option<String> {
+    let soname_regex = Regex::new(r"^libsystemd-shared-(\d+)[.-].*so$").unwrap();
+
+    let captures = soname_regex.captures(name)?;
+    Some(captures[1].to_owned())
+}
+
+fn systemd_version(root: &Path) -> Option<String> {
+    SYSTEMD_DIRS.iter()
+        .filter_map(|dir| fs::read_dir(utils::rooted(root, dir)).ok())
+        .flat_map(|entries| entries.filter_map(|entry| entry.ok()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|name| parse_systemd_shared_version(&name))
+        .next()
+}
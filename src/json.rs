@@ -0,0 +1,187 @@
+/*
+ * Minimal JSON parsing for the status files some checks read, to avoid
+ * depending on a JSON library
+ */
+use std::iter::Peekable;
+use std::str::Chars;
+
+// Deeper documents are rejected instead of risking a stack overflow
+const MAX_DEPTH: usize = 64;
+
+/// A parsed JSON value. Object members keep their order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Returns the member `key` of an object
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match *self {
+            Value::Object(ref members) => members.iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Value::Bool(value) => Some(value),
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Value::String(ref value) => Some(value),
+            _ => None
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match *self {
+            Value::Array(ref values) => Some(values),
+            _ => None
+        }
+    }
+}
+
+/// Parses a JSON document, returning `None` if it is malformed
+pub fn parse(text: &str) -> Option<Value> {
+    let mut parser = Parser { chars: text.chars().peekable() };
+    let value = parser.value(0)?;
+    parser.skip_whitespace();
+    match parser.chars.peek() {
+        None => Some(value),
+        Some(_) => None
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        self.skip_whitespace();
+        match *self.chars.peek()? {
+            '{' => self.object(depth),
+            '[' => self.array(depth),
+            '"' => self.string().map(Value::String),
+            't' => self.literal("true", Value::Bool(true)),
+            'f' => self.literal("false", Value::Bool(false)),
+            'n' => self.literal("null", Value::Null),
+            _ => self.number(),
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Option<Value> {
+        self.chars.next();
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.chars.next();
+            return Some(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            if self.chars.next()? != ':' {
+                return None;
+            }
+            members.push((key, self.value(depth + 1)?));
+            self.skip_whitespace();
+            match self.chars.next()? {
+                ',' => continue,
+                '}' => return Some(Value::Object(members)),
+                _ => return None
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Option<Value> {
+        self.chars.next();
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.chars.next();
+            return Some(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value(depth + 1)?);
+            self.skip_whitespace();
+            match self.chars.next()? {
+                ',' => continue,
+                ']' => return Some(Value::Array(values)),
+                _ => return None
+            }
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.chars.next()? != '"' {
+            return None;
+        }
+
+        let mut string = String::new();
+        loop {
+            let c = match self.chars.next()? {
+                '"' => return Some(string),
+                '\\' => match self.chars.next()? {
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => {
+                        let hex = (0..4).map(|_| self.chars.next()).collect::<Option<String>>()?;
+                        // Surrogate pairs aren't combined, none of the keys read need them
+                        char::from_u32(u32::from_str_radix(&hex, 16).ok()?).unwrap_or('\u{fffd}')
+                    }
+                    c => c
+                },
+                c => c
+            };
+            string.push(c);
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Option<Value> {
+        for expected in literal.chars() {
+            if self.chars.next()? != expected {
+                return None;
+            }
+        }
+        Some(value)
+    }
+
+    fn number(&mut self) -> Option<Value> {
+        let mut number = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !c.is_ascii_digit() && !"+-.eE".contains(c) {
+                break;
+            }
+            number.push(c);
+            self.chars.next();
+        }
+        number.parse().ok().map(Value::Number)
+    }
+}
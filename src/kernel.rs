@@ -0,0 +1,132 @@
+/*
+ * Kernel related checks
+ */
+use regex::Regex;
+use uname;
+use utils;
+
+/// A kernel release split into its numeric version and local suffix
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KernelVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// Everything after the numeric version, e.g. `-91-generic`
+    pub local: Option<String>,
+}
+
+/// The kernel flavour, as encoded in the release or build string
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum KernelFlavour {
+    Generic,
+    LowLatency,
+    /// A `PREEMPT_RT` kernel
+    RealTime,
+    #[cfg_attr(feature = "serde", serde(rename = "aws"))]
+    AWS,
+    Azure,
+    #[cfg_attr(feature = "serde", serde(rename = "wsl"))]
+    WSL,
+    Grsecurity,
+}
+
+/// Holds information about the running kernel
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KernelInfo {
+    /// The kernel release, e.g. `5.15.0-91-generic`
+    pub release: String,
+    pub version: Option<KernelVersion>,
+    /// The build string, e.g. `#101-Ubuntu SMP Tue Nov 14 13:30:08 UTC 2023`
+    pub build: Option<String>,
+    /// The machine hardware name, e.g. `x86_64`
+    pub architecture: Option<String>,
+    pub flavour: KernelFlavour,
+    /// `true` for kernels built by the distribution, `false` for custom builds
+    pub distro_kernel: bool,
+}
+
+/// Describes the running kernel, falling back to the output of `uname`
+/// where `/proc` isn't available
+pub fn retrieve(uname: Option<&uname::Uname>) -> Option<KernelInfo> {
+    let release = utils::read_file("/proc/sys/kernel/osrelease")
+        .ok()
+        .map(|release| release.trim().to_owned())
+        .or_else(|| uname.and_then(|uname| uname.release.clone()))?;
+    let build = utils::read_file("/proc/sys/kernel/version")
+        .ok()
+        .map(|build| build.trim().to_owned())
+        .or_else(uname::retrieve_version);
+    let proc_version = utils::read_file("/proc/version").unwrap_or_default();
+
+    Some(KernelInfo {
+        version: parse_version(&release),
+        flavour: parse_flavour(&release, build.as_ref().map_or("", String::as_str)),
+        distro_kernel: is_distro_kernel(&release, &proc_version),
+        architecture: uname.and_then(|uname| uname.machine.clone()),
+        release,
+        build,
+    })
+}
+
+/// Parses a kernel release like `5.15.0-91-generic` or `6.7.0-rc3+`
+pub fn parse_version(release: &str) -> Option<KernelVersion> {
+    let version_regex = Regex::new(r"^(\d+)\.(\d+)(?:\.(\d+))?(.*)$").unwrap();
+
+    let captures = version_regex.captures(release.trim())?;
+    Some(KernelVersion {
+        major: captures[1].parse().ok()?,
+        minor: captures[2].parse().ok()?,
+        patch: captures.get(3).map_or(Some(0), |patch| patch.as_str().parse().ok())?,
+        local: captures.get(4)
+            .map(|local| local.as_str().to_owned())
+            .filter(|local| !local.is_empty()),
+    })
+}
+
+/// Classifies the kernel flavour from its release and build string
+pub fn parse_flavour(release: &str, build: &str) -> KernelFlavour {
+    let release = release.to_lowercase();
+    // An `rt` release component, e.g. -rt18, Debian's -rt-amd64 or RHEL's .rt7
+    let realtime_regex = Regex::new(r"[-.]rt\d*(?:[-.+]|$)").unwrap();
+
+    if release.contains("microsoft") {
+        KernelFlavour::WSL
+    } else if build.contains("PREEMPT_RT") || realtime_regex.is_match(&release) || release.ends_with("-realtime") {
+        KernelFlavour::RealTime
+    } else if release.ends_with("-lowlatency") {
+        KernelFlavour::LowLatency
+    } else if release.ends_with("-aws") {
+        KernelFlavour::AWS
+    } else if release.ends_with("-azure") {
+        KernelFlavour::Azure
+    } else if release.contains("grsec") {
+        KernelFlavour::Grsecurity
+    } else {
+        KernelFlavour::Generic
+    }
+}
+
+/// Tells distribution kernels from custom builds, based on the release
+/// suffix distributions add and the build host recorded in `/proc/version`
+pub fn is_distro_kernel(release: &str, proc_version: &str) -> bool {
+    let distro_release_regex = Regex::new(
+        r"(?x)
+        -\d+-[a-z0-9-]+$              # Ubuntu and Debian, e.g. -91-generic or -17-amd64
+        | \.(el|fc|amzn|mga)\d+       # Red Hat family, e.g. .el8 or .fc39
+        | -arch\d+-\d+$               # Arch Linux
+        | -(default|lts|virt)$        # SUSE and Alpine
+        | -microsoft-standard         # WSL
+        ",
+    ).unwrap();
+    let distro_builders = [
+        "buildd@", "debian-kernel@", "mockbuild@", "linux@archlinux", "abuild@", "geeko@buildhost",
+    ];
+
+    distro_release_regex.is_match(release.trim())
+        || distro_builders.iter().any(|builder| proc_version.contains(builder))
+}
@@ -1,12 +1,66 @@
 extern crate regex;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
-use std::process::Command;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+mod android;
+mod architecture;
+mod bsd;
+mod chromeos;
+mod chroot;
+mod cloud;
+mod codename;
+mod container;
+mod efivars;
+mod elf;
+mod firmware;
+mod illumos_release;
+mod immutable;
+mod init_system;
+mod json;
+mod kernel;
+mod libc;
+mod lifecycle;
 mod lsb_release;
 mod os_release;
+mod package_commands;
+mod package_manager;
 mod rhel_release;
+mod sandbox;
+mod security;
 mod sw_vers;
+mod ubuntu;
+mod uname;
 mod utils;
+mod virtualization;
+#[allow(dead_code)]
 mod windows_ver;
+mod wsl;
+
+pub use android::AndroidRelease;
+pub use architecture::{Architecture, ArchitectureInfo};
+pub use bsd::{BSDRelease, BSDVersion};
+pub use chromeos::ChromeOSRelease;
+pub use cloud::{CloudInfo, CloudProvider};
+pub use firmware::{BootMode, Bootloader, FirmwareInfo};
+pub use immutable::{ImmutableInfo, ImmutableOS};
+pub use init_system::{InitInfo, InitSystem};
+pub use kernel::{KernelFlavour, KernelInfo, KernelVersion};
+pub use libc::{LibC, LibCInfo};
+pub use lifecycle::{Date, ExtendedSupport, Lifecycle, LifecycleStatus};
+pub use package_commands::{PackageCommand, PackageCommands, PackageNames};
+pub use package_manager::{PackageFormat, PackageManager, PackageManagerInfo};
+pub use container::{CGroupVersion, ContainerEnv, ContainerInfo, ContainerRuntime, ContainerTool};
+pub use sandbox::{Sandbox, SandboxInfo};
+pub use security::{LockdownMode, SELinuxInfo, SELinuxMode, SecurityInfo};
+pub use sw_vers::{MacOSProductName, MacOSRelease};
+pub use ubuntu::{UbuntuFlavour, UbuntuInfo, UbuntuPro, UbuntuReleaseType};
+pub use virtualization::Virtualization;
+pub use wsl::{WSLGeneration, WSLInfo};
 
 ///A list of supported operating system types
 #[derive(Debug, PartialEq, Clone)]
@@ -24,14 +78,502 @@ pub enum OSType {
     Deepin,
     NixOS,
     Kali,
+    FreeBSD,
+    OpenBSD,
+    NetBSD,
+    DragonFly,
+    OpenIndiana,
+    OmniOS,
+    SmartOS,
+    Solaris,
+    Haiku,
+    /// Android, with its API level if known
+    Android(Option<u32>),
+    ChromeOS,
+}
+
+impl fmt::Display for OSType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+///Serializes to the canonical ID returned by `OSType::id`, with the API
+///level appended for Android, e.g. `android-34`
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for OSType {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            OSType::Android(Some(api_level)) => serializer.collect_str(&format_args!("android-{}", api_level)),
+            _ => serializer.serialize_str(self.id()),
+        }
+    }
+}
+
+///Only accepts canonical IDs and `android-<API level>`, unlike `FromStr`
+///which also takes names and aliases
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for OSType {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<OSType, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        let api_level = id.strip_prefix("android-").and_then(|level| level.parse().ok());
+        api_level
+            .map(|api_level| OSType::Android(Some(api_level)))
+            .or_else(|| OSType::all().find(|os_type| os_type.id() == id))
+            .ok_or_else(|| ::serde::de::Error::custom(ParseOSTypeError { name: id }))
+    }
+}
+
+///Returned when a string names no known operating system
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOSTypeError {
+    name: String,
+}
+
+impl fmt::Display for ParseOSTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown operating system `{}`", self.name)
+    }
+}
+
+impl Error for ParseOSTypeError {}
+
+impl FromStr for OSType {
+    type Err = ParseOSTypeError;
+
+    ///Parses canonical IDs, names and common aliases like `redhat`,
+    ///`darwin` or `osx`, ignoring case
+    fn from_str(name: &str) -> Result<OSType, ParseOSTypeError> {
+        let normalized = name.trim().to_lowercase();
+        let alias = match normalized.as_str() {
+            "redhat" | "red hat" | "red hat enterprise linux server" => Some(OSType::Redhat),
+            "darwin" | "osx" | "os x" | "mac os x" | "mac" => Some(OSType::OSX),
+            "archlinux" => Some(OSType::Arch),
+            "opensuse-leap" | "opensuse-tumbleweed" | "suse" => Some(OSType::OpenSUSE),
+            "dragonflybsd" | "dragonfly" => Some(OSType::DragonFly),
+            "sunos" | "oracle solaris" => Some(OSType::Solaris),
+            "chrome os" | "chromium os" => Some(OSType::ChromeOS),
+            "kali linux" | "kali-linux" => Some(OSType::Kali),
+            _ => None,
+        };
+
+        alias
+            .or_else(|| {
+                OSType::all().find(|os_type| {
+                    os_type.id() == normalized || os_type.name().to_lowercase() == normalized
+                })
+            })
+            .ok_or_else(|| ParseOSTypeError { name: name.to_owned() })
+    }
+}
+
+///Groups operating systems which share their packaging and most of
+///their tooling
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum OSFamily {
+    Unknown,
+    Debian,
+    #[cfg_attr(feature = "serde", serde(rename = "rhel"))]
+    RedHat,
+    Suse,
+    Arch,
+    Alpine,
+    #[cfg_attr(feature = "serde", serde(rename = "nixos"))]
+    NixOS,
+    #[cfg_attr(feature = "serde", serde(rename = "bsd"))]
+    BSD,
+    Solaris,
+    Darwin,
+    Android,
+    #[cfg_attr(feature = "serde", serde(rename = "chromeos"))]
+    ChromeOS,
+    Haiku,
+}
+
+static ALL_OS_TYPES: &[OSType] = &[
+    OSType::Unknown,
+    OSType::Redhat,
+    OSType::OSX,
+    OSType::Ubuntu,
+    OSType::Debian,
+    OSType::Arch,
+    OSType::Manjaro,
+    OSType::CentOS,
+    OSType::OpenSUSE,
+    OSType::Alpine,
+    OSType::Deepin,
+    OSType::NixOS,
+    OSType::Kali,
+    OSType::FreeBSD,
+    OSType::OpenBSD,
+    OSType::NetBSD,
+    OSType::DragonFly,
+    OSType::OpenIndiana,
+    OSType::OmniOS,
+    OSType::SmartOS,
+    OSType::Solaris,
+    OSType::Haiku,
+    OSType::Android(None),
+    OSType::ChromeOS,
+];
+
+impl OSType {
+    ///Returns all operating system types, with `Android` carrying no API
+    ///level
+    pub fn all() -> impl Iterator<Item = OSType> {
+        ALL_OS_TYPES.iter().cloned()
+    }
+
+    ///Returns the canonical lowercase identifier, matching the `ID` field
+    ///of os-release where the system has one, e.g. `rhel` or `macos`
+    pub fn id(&self) -> &'static str {
+        match *self {
+            OSType::Unknown => "unknown",
+            OSType::Redhat => "rhel",
+            OSType::OSX => "macos",
+            OSType::Ubuntu => "ubuntu",
+            OSType::Debian => "debian",
+            OSType::Arch => "arch",
+            OSType::Manjaro => "manjaro",
+            OSType::CentOS => "centos",
+            OSType::OpenSUSE => "opensuse",
+            OSType::Alpine => "alpine",
+            OSType::Deepin => "deepin",
+            OSType::NixOS => "nixos",
+            OSType::Kali => "kali",
+            OSType::FreeBSD => "freebsd",
+            OSType::OpenBSD => "openbsd",
+            OSType::NetBSD => "netbsd",
+            OSType::DragonFly => "dragonfly",
+            OSType::OpenIndiana => "openindiana",
+            OSType::OmniOS => "omnios",
+            OSType::SmartOS => "smartos",
+            OSType::Solaris => "solaris",
+            OSType::Haiku => "haiku",
+            OSType::Android(_) => "android",
+            OSType::ChromeOS => "chromeos",
+        }
+    }
+
+    ///Returns the name the vendor uses, e.g. `Red Hat Enterprise Linux`
+    pub fn name(&self) -> &'static str {
+        match *self {
+            OSType::Unknown => "Unknown",
+            OSType::Redhat => "Red Hat Enterprise Linux",
+            OSType::OSX => "macOS",
+            OSType::Ubuntu => "Ubuntu",
+            OSType::Debian => "Debian",
+            OSType::Arch => "Arch Linux",
+            OSType::Manjaro => "Manjaro",
+            OSType::CentOS => "CentOS",
+            OSType::OpenSUSE => "openSUSE",
+            OSType::Alpine => "Alpine Linux",
+            OSType::Deepin => "Deepin",
+            OSType::NixOS => "NixOS",
+            OSType::Kali => "Kali Linux",
+            OSType::FreeBSD => "FreeBSD",
+            OSType::OpenBSD => "OpenBSD",
+            OSType::NetBSD => "NetBSD",
+            OSType::DragonFly => "DragonFly BSD",
+            OSType::OpenIndiana => "OpenIndiana",
+            OSType::OmniOS => "OmniOS",
+            OSType::SmartOS => "SmartOS",
+            OSType::Solaris => "Oracle Solaris",
+            OSType::Haiku => "Haiku",
+            OSType::Android(_) => "Android",
+            OSType::ChromeOS => "ChromeOS",
+        }
+    }
+
+    ///Returns the codename of a release from the bundled table, e.g.
+    ///`bookworm` for Debian `12`
+    pub fn codename(&self, version: &str) -> Option<&'static str> {
+        distro_id(self, version).and_then(|distro| codename::lookup(distro, version))
+    }
+
+    ///Returns the release carrying a codename, e.g. `22.04` for Ubuntu
+    ///`jammy`
+    pub fn version_for_codename(&self, codename: &str) -> Option<&'static str> {
+        distro_id(self, "").and_then(|distro| codename::version(distro, codename))
+    }
+
+    ///Returns the family the operating system belongs to, e.g. `Debian`
+    ///for Ubuntu
+    pub fn family(&self) -> OSFamily {
+        match *self {
+            OSType::Unknown => OSFamily::Unknown,
+            OSType::Ubuntu | OSType::Debian | OSType::Kali | OSType::Deepin => OSFamily::Debian,
+            OSType::Redhat | OSType::CentOS => OSFamily::RedHat,
+            OSType::OpenSUSE => OSFamily::Suse,
+            OSType::Arch | OSType::Manjaro => OSFamily::Arch,
+            OSType::Alpine => OSFamily::Alpine,
+            OSType::NixOS => OSFamily::NixOS,
+            OSType::FreeBSD | OSType::OpenBSD | OSType::NetBSD | OSType::DragonFly => OSFamily::BSD,
+            OSType::OpenIndiana | OSType::OmniOS | OSType::SmartOS | OSType::Solaris => OSFamily::Solaris,
+            OSType::OSX => OSFamily::Darwin,
+            OSType::Android(_) => OSFamily::Android,
+            OSType::ChromeOS => OSFamily::ChromeOS,
+            OSType::Haiku => OSFamily::Haiku,
+        }
+    }
+}
+
+impl OSFamily {
+    ///Returns the package format the family ships its packages in
+    pub fn package_format(&self) -> Option<PackageFormat> {
+        match *self {
+            OSFamily::Debian => Some(PackageFormat::Deb),
+            OSFamily::RedHat | OSFamily::Suse => Some(PackageFormat::Rpm),
+            OSFamily::Arch => Some(PackageFormat::Pacman),
+            OSFamily::Alpine => Some(PackageFormat::Apk),
+            OSFamily::NixOS => Some(PackageFormat::Nix),
+            OSFamily::BSD => Some(PackageFormat::Pkg),
+            _ => None,
+        }
+    }
 }
 
 /// Holds information about Operating System type and its version
 /// If the version could not be fetched it defaults to `0.0.0`
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OSInformation {
     pub os_type: self::OSType,
     pub version: String,
+    codename: Option<String>,
+    os_release_id: Option<String>,
+    macos: Option<MacOSRelease>,
+    bsd: Option<BSDRelease>,
+    android: Option<AndroidRelease>,
+    chromeos: Option<ChromeOSRelease>,
+    wsl: Option<WSLInfo>,
+    container: Option<ContainerInfo>,
+    virtualization: Virtualization,
+    cloud: Option<CloudInfo>,
+    kernel: Option<KernelInfo>,
+    architecture: Option<ArchitectureInfo>,
+    libc: Option<LibCInfo>,
+    init: Option<InitInfo>,
+    package_managers: Option<PackageManagerInfo>,
+    ubuntu: Option<UbuntuInfo>,
+    immutable: Option<ImmutableInfo>,
+    sandbox: Option<SandboxInfo>,
+    host: Option<Box<OSInformation>>,
+    chroot: Option<bool>,
+    security: Option<SecurityInfo>,
+    firmware: Option<FirmwareInfo>,
+}
+
+impl OSInformation {
+    fn new(os_type: OSType, version: Option<String>, codename: Option<String>) -> OSInformation {
+        OSInformation {
+            os_type,
+            version: version.unwrap_or_else(default_version),
+            codename,
+            os_release_id: None,
+            macos: None,
+            bsd: None,
+            android: None,
+            chromeos: None,
+            wsl: None,
+            container: None,
+            virtualization: Virtualization::Unknown,
+            cloud: None,
+            kernel: None,
+            architecture: None,
+            libc: None,
+            init: None,
+            package_managers: None,
+            ubuntu: None,
+            immutable: None,
+            sandbox: None,
+            host: None,
+            chroot: None,
+            security: None,
+            firmware: None,
+        }
+    }
+
+    ///Returns the release codename, e.g. `jammy` for Ubuntu 22.04 or
+    ///`Sonoma` for macOS 14, if it is known. When the system doesn't
+    ///report one, it is taken from the bundled codename table.
+    pub fn codename(&self) -> Option<&str> {
+        self.codename.as_deref()
+    }
+
+    ///Returns the `ID` field of os-release, e.g. `fedora`. It is also kept
+    ///for distributions `OSType` doesn't list, which are `Unknown`.
+    pub fn os_release_id(&self) -> Option<&str> {
+        self.os_release_id.as_deref()
+    }
+
+    ///Returns macOS specific release information, `None` on other systems
+    pub fn macos(&self) -> Option<&MacOSRelease> {
+        self.macos.as_ref()
+    }
+
+    ///Returns BSD specific release information, including the FreeBSD
+    ///patch level of kernel and userland. `None` on other systems
+    pub fn bsd(&self) -> Option<&BSDRelease> {
+        self.bsd.as_ref()
+    }
+
+    ///Returns Android specific release information, `None` on other systems
+    pub fn android(&self) -> Option<&AndroidRelease> {
+        self.android.as_ref()
+    }
+
+    ///Returns ChromeOS specific release information. Inside a Crostini
+    ///container `os_type` describes the Linux guest, while this reports
+    ///the ChromeOS host milestone
+    pub fn chromeos(&self) -> Option<&ChromeOSRelease> {
+        self.chromeos.as_ref()
+    }
+
+    ///Returns the WSL generation and distribution name when running under
+    ///the Windows Subsystem for Linux. `os_type` still describes the Linux
+    ///distribution
+    pub fn wsl(&self) -> Option<&WSLInfo> {
+        self.wsl.as_ref()
+    }
+
+    ///Returns the container runtime when running inside a container.
+    ///`os_type` then describes the container image, not the host
+    pub fn container(&self) -> Option<&ContainerInfo> {
+        self.container.as_ref()
+    }
+
+    ///Returns the hypervisor the system runs on, or `BareMetal`
+    pub fn virtualization(&self) -> &Virtualization {
+        &self.virtualization
+    }
+
+    ///Returns the cloud provider the instance runs at. Only local files
+    ///are inspected, the instance metadata service is never contacted
+    pub fn cloud(&self) -> Option<&CloudInfo> {
+        self.cloud.as_ref()
+    }
+
+    ///Returns information about the running kernel
+    pub fn kernel(&self) -> Option<&KernelInfo> {
+        self.kernel.as_ref()
+    }
+
+    ///Returns the kernel and userland architecture. Use the userland
+    ///architecture to pick binaries, the kernel may be 64-bit while
+    ///programs are 32-bit
+    pub fn architecture(&self) -> Option<&ArchitectureInfo> {
+        self.architecture.as_ref()
+    }
+
+    ///Returns the system C library and its version. It is determined from
+    ///the files on disk, without linking against it
+    pub fn libc(&self) -> Option<&LibCInfo> {
+        self.libc.as_ref()
+    }
+
+    ///Returns the init system and service manager
+    pub fn init(&self) -> Option<&InitInfo> {
+        self.init.as_ref()
+    }
+
+    ///Returns the available package managers and the native package
+    ///format, based on the package databases found on disk
+    pub fn package_managers(&self) -> Option<&PackageManagerInfo> {
+        self.package_managers.as_ref()
+    }
+
+    ///Returns the release type, flavour, HWE and Ubuntu Pro status on
+    ///Ubuntu
+    pub fn ubuntu(&self) -> Option<&UbuntuInfo> {
+        self.ubuntu.as_ref()
+    }
+
+    ///Returns the image details of immutable systems like Fedora Silverblue,
+    ///Fedora CoreOS, Flatcar, Bottlerocket or Talos
+    pub fn immutable(&self) -> Option<&ImmutableInfo> {
+        self.immutable.as_ref()
+    }
+
+    ///Returns the Flatpak, Snap or AppImage the process runs from
+    pub fn sandbox(&self) -> Option<&SandboxInfo> {
+        self.sandbox.as_ref()
+    }
+
+    ///Returns the host operating system when running inside a sandbox or
+    ///a Toolbox or Distrobox container, in which case the other fields
+    ///describe the sandbox runtime or the container's guest system
+    pub fn host(&self) -> Option<&OSInformation> {
+        self.host.as_deref()
+    }
+
+    ///Returns `true` inside a chroot, `None` if that couldn't be determined
+    ///because PID 1's root directory wasn't accessible
+    pub fn chroot(&self) -> Option<bool> {
+        self.chroot
+    }
+
+    ///Returns the SELinux, AppArmor, FIPS, kernel lockdown and Secure Boot
+    ///state. `None` on systems other than Linux.
+    pub fn security(&self) -> Option<&SecurityInfo> {
+        self.security.as_ref()
+    }
+
+    ///Returns the boot mode, UEFI details and the boot loader. `None` on
+    ///systems other than Linux.
+    pub fn firmware(&self) -> Option<&FirmwareInfo> {
+        self.firmware.as_ref()
+    }
+
+    ///Returns the install, update and query commands for the native
+    ///package manager, or Homebrew if there is no native one, as on macOS.
+    ///Immutable systems get none, as packages can't be installed into
+    ///their read-only image.
+    ///On NixOS, packages are installed from the `nixos` channel.
+    pub fn package_commands(&self) -> Option<PackageCommands> {
+        if self.immutable.is_some() {
+            return None;
+        }
+        let managers = self.package_managers.as_ref()?;
+        managers.native.first()
+            .or_else(|| managers.secondary.iter().find(|&&manager| manager == PackageManager::Homebrew))
+            .map(|&manager| {
+                let mut commands = PackageCommands::new(manager);
+                if self.os_type == OSType::NixOS {
+                    commands.nix_channel = "nixos".to_owned();
+                }
+                commands
+            })
+    }
+
+    ///Returns the release and support dates of this release from the
+    ///bundled lifecycle table
+    pub fn release_lifecycle(&self) -> Option<Lifecycle> {
+        distro_id(&self.os_type, &self.version)
+            .and_then(|distro| lifecycle::lookup(distro, &self.version))
+    }
+
+    ///Returns whether this release is supported, only receives security
+    ///fixes or is end of life on `date`, e.g. `Date::today()`
+    pub fn lifecycle(&self, date: Date) -> Option<LifecycleStatus> {
+        self.release_lifecycle().map(|lifecycle| lifecycle.status(date))
+    }
+}
+
+///Returns the os-release style ID of a distribution, as used by the
+///bundled codename and lifecycle tables
+fn distro_id(os_type: &OSType, version: &str) -> Option<&'static str> {
+    match *os_type {
+        OSType::Unknown => None,
+        // Tumbleweed is versioned by its snapshot date, e.g. 20240115
+        OSType::OpenSUSE if version.len() == 8 && version.chars().all(|c| c.is_ascii_digit()) => {
+            Some("opensuse-tumbleweed")
+        }
+        OSType::OpenSUSE => Some("opensuse-leap"),
+        _ => Some(os_type.id()),
+    }
 }
 
 fn default_version() -> String {
@@ -39,76 +581,134 @@ fn default_version() -> String {
 }
 
 fn unknown_os() -> OSInformation {
-    OSInformation {
-        os_type: OSType::Unknown,
-        version: default_version(),
+    OSInformation::new(OSType::Unknown, None, None)
+}
+
+fn get_sw_vers() -> OSInformation {
+    if let Some(osx_info) = sw_vers::retrieve() {
+        let version = osx_info.product_version.unwrap_or_else(default_version);
+        let server = osx_info.product_name.is_some_and(|name| sw_vers::is_server(&name))
+            || utils::file_exists("/System/Library/CoreServices/ServerVersion.plist");
+        let codename = codename::macos(&version).map(String::from);
+        let mut info = OSInformation::new(OSType::OSX, Some(version.clone()), codename);
+        info.macos = Some(MacOSRelease {
+            product_name: sw_vers::product_name(&version),
+            build_version: osx_info.build_version,
+            server,
+        });
+        info
+    } else {
+        unknown_os()
     }
 }
 
-fn is_os_x() -> bool {
-    match Command::new("sw_vers").output() {
-        Ok(output) => output.status.success(),
-        Err(_) => false,
+fn uname_release(uname: &uname::Uname) -> Option<OSInformation> {
+    match uname.sysname.as_deref() {
+        Some("FreeBSD") | Some("OpenBSD") | Some("NetBSD") | Some("DragonFly") => {
+            Some(bsd_release(uname))
+        }
+        Some("SunOS") => Some(illumos_release()),
+        Some("Haiku") => Some(haiku_release()),
+        _ => None,
     }
 }
 
-fn get_sw_vers() -> OSInformation {
-    if let Some(osx_info) = sw_vers::retrieve() {
-        OSInformation {
-            os_type: OSType::OSX,
-            version: osx_info.product_version.unwrap_or(default_version()),
+fn bsd_release(uname: &uname::Uname) -> OSInformation {
+    let os_type = match uname.sysname.as_deref() {
+        Some("FreeBSD") => OSType::FreeBSD,
+        Some("OpenBSD") => OSType::OpenBSD,
+        Some("NetBSD") => OSType::NetBSD,
+        Some("DragonFly") => OSType::DragonFly,
+        _ => return unknown_os(),
+    };
+
+    let mut kernel = uname.release.as_ref().and_then(|release| bsd::parse_version(release));
+    let mut userland = None;
+    if os_type == OSType::FreeBSD {
+        if let Some(freebsd) = bsd::retrieve_freebsd_version() {
+            kernel = freebsd.kernel.and_then(|release| bsd::parse_version(&release)).or(kernel);
+            userland = freebsd.userland.and_then(|release| bsd::parse_version(&release));
+        } else {
+            userland = bsd::retrieve_os_release_version()
+                .and_then(|release| bsd::parse_version(&release));
         }
-    } else {
-        unknown_os()
+    }
+
+    let version = userland.as_ref().or(kernel.as_ref()).map(|release| release.version.clone());
+    let mut info = OSInformation::new(os_type, version, None);
+    info.bsd = Some(BSDRelease { kernel, userland });
+    info
+}
+
+fn illumos_release() -> OSInformation {
+    match illumos_release::retrieve() {
+        Some(release) => {
+            let os_type = match release.distro.as_deref() {
+                Some("OmniOS") => OSType::OmniOS,
+                Some("OpenIndiana") => OSType::OpenIndiana,
+                Some("SmartOS") => OSType::SmartOS,
+                Some("Solaris") => OSType::Solaris,
+                _ => return unknown_os(),
+            };
+            OSInformation::new(os_type, release.version, None)
+        }
+        None => unknown_os(),
+    }
+}
+
+fn haiku_release() -> OSInformation {
+    let revision = uname::retrieve_version().and_then(|version| uname::parse_haiku_revision(&version));
+    OSInformation::new(OSType::Haiku, revision, None)
+}
+
+fn android_release() -> OSInformation {
+    match android::retrieve() {
+        Some(build_prop) => {
+            let api_level = build_prop.sdk.and_then(|sdk| sdk.parse().ok());
+            let mut info = OSInformation::new(OSType::Android(api_level), build_prop.release, None);
+            info.android = Some(AndroidRelease {
+                api_level,
+                manufacturer: build_prop.manufacturer,
+                security_patch: build_prop.security_patch,
+                termux: android::is_termux(),
+            });
+            info
+        }
+        None => unknown_os(),
+    }
+}
+
+fn chromeos_release() -> OSInformation {
+    match chromeos::retrieve() {
+        Some(release) => {
+            let mut info = OSInformation::new(OSType::ChromeOS, release.version, None);
+            info.chromeos = Some(ChromeOSRelease {
+                milestone: release.milestone.and_then(|milestone| chromeos::parse_milestone(&milestone)),
+                board: release.board,
+                track: release.track,
+                crostini: false,
+            });
+            info
+        }
+        None => unknown_os(),
     }
 }
 
 fn lsb_release() -> OSInformation {
     match lsb_release::retrieve() {
         Some(release) => {
-            if release.distro == Some("Ubuntu".to_string()) {
-                OSInformation {
-                    os_type: OSType::Ubuntu,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("Debian".to_string()) {
-                OSInformation {
-                    os_type: OSType::Debian,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("Arch".to_string()) {
-                OSInformation {
-                    os_type: OSType::Arch,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("ManjaroLinux".to_string()) {
-                OSInformation {
-                    os_type: OSType::Manjaro,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("CentOS".to_string()) {
-                OSInformation {
-                    os_type: OSType::CentOS,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("openSUSE".to_string()) {
-                OSInformation {
-                    os_type: OSType::OpenSUSE,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("NixOS".to_string()) {
-                OSInformation {
-                    os_type: OSType::NixOS,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else if release.distro == Some("Kali".to_string()) {
-                OSInformation {
-                    os_type: OSType::Kali,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            } else {
-                unknown_os()
-            }
+            let os_type = match release.distro.as_deref() {
+                Some("Ubuntu") => OSType::Ubuntu,
+                Some("Debian") => OSType::Debian,
+                Some("Arch") => OSType::Arch,
+                Some("ManjaroLinux") => OSType::Manjaro,
+                Some("CentOS") => OSType::CentOS,
+                Some("openSUSE") => OSType::OpenSUSE,
+                Some("NixOS") => OSType::NixOS,
+                Some("Kali") => OSType::Kali,
+                _ => return unknown_os(),
+            };
+            OSInformation::new(os_type, release.version, release.codename)
         }
         None => unknown_os(),
     }
@@ -117,79 +717,66 @@ fn lsb_release() -> OSInformation {
 fn rhel_release() -> OSInformation {
     match rhel_release::retrieve() {
         Some(release) => {
-            if release.distro == Some("CentOS".to_string()) {
-                OSInformation {
-                    os_type: OSType::CentOS,
-                    version: release.version.unwrap_or(default_version()),
-                }
+            let os_type = if release.distro == Some("CentOS".to_string()) {
+                OSType::CentOS
             } else {
-                OSInformation {
-                    os_type: OSType::Redhat,
-                    version: release.version.unwrap_or(default_version()),
-                }
-            }
+                OSType::Redhat
+            };
+            OSInformation::new(os_type, release.version, release.codename)
         }
         None => unknown_os(),
     }
 }
 
 fn os_release() -> OSInformation {
-    match os_release::retrieve() {
-        Some(release) => match release.distro {
-            Some(distro) => {
-                if distro.starts_with("Ubuntu") {
-                    OSInformation {
-                        os_type: OSType::Ubuntu,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("Debian") {
-                    OSInformation {
-                        os_type: OSType::Debian,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("Arch") {
-                    OSInformation {
-                        os_type: OSType::Arch,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("CentOS") {
-                    OSInformation {
-                        os_type: OSType::CentOS,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("openSUSE") {
-                    OSInformation {
-                        os_type: OSType::OpenSUSE,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("Alpine") {
-                    OSInformation {
-                        os_type: OSType::Alpine,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("Deepin") {
-                    OSInformation {
-                        os_type: OSType::Deepin,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("NixOS") {
-                    OSInformation {
-                        os_type: OSType::NixOS,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else if distro.starts_with("Kali") {
-                    OSInformation {
-                        os_type: OSType::Kali,
-                        version: release.version.unwrap_or(default_version()),
-                    }
-                } else {
-                    unknown_os()
-                }
-            }
-            None => unknown_os(),
-        },
-        None => unknown_os(),
+    os_release::retrieve().map_or_else(unknown_os, os_release_info)
+}
+
+///Maps an os-release to an operating system. Distributions which aren't
+///listed keep their version and `ID` with `OSType::Unknown`.
+fn os_release_info(release: os_release::OSRelease) -> OSInformation {
+    let distro = release.distro.unwrap_or_default();
+    let os_type = if distro.starts_with("Ubuntu") {
+        OSType::Ubuntu
+    } else if distro.starts_with("Debian") {
+        OSType::Debian
+    } else if distro.starts_with("Arch") {
+        OSType::Arch
+    } else if distro.starts_with("CentOS") {
+        OSType::CentOS
+    } else if distro.starts_with("openSUSE") {
+        OSType::OpenSUSE
+    } else if distro.starts_with("Alpine") {
+        OSType::Alpine
+    } else if distro.starts_with("Deepin") {
+        OSType::Deepin
+    } else if distro.starts_with("NixOS") {
+        OSType::NixOS
+    } else if distro.starts_with("Kali") {
+        OSType::Kali
+    } else if distro.starts_with("FreeBSD") {
+        OSType::FreeBSD
+    } else if release.id.is_some() {
+        OSType::Unknown
+    } else {
+        return unknown_os();
+    };
+    let mut info = OSInformation::new(os_type, release.version, release.codename);
+    info.os_release_id = release.id;
+    info
+}
+
+///Identifies the host from the os-release a sandbox or container exposes
+fn host_platform() -> Option<OSInformation> {
+    sandbox::retrieve_host_os_release().map(host_info)
+}
+
+fn host_info(os_release: String) -> OSInformation {
+    let mut host = os_release_info(os_release::parse(os_release));
+    if host.codename.is_none() {
+        host.codename = host.os_type.codename(&host.version).map(String::from);
     }
+    host
 }
 
 ///Returns the current operating system type
@@ -203,8 +790,54 @@ fn os_release() -> OSInformation {
 ///println!("Version: {}", os.version);
 ///```
 pub fn current_platform() -> OSInformation {
-    if is_os_x() {
+    let uname = uname::retrieve();
+    let mut info = detect_platform(uname.as_ref());
+    if info.codename.is_none() {
+        info.codename = info.os_type.codename(&info.version).map(String::from);
+    }
+    if info.os_type != OSType::ChromeOS && chromeos::is_crostini() {
+        info.chromeos = Some(ChromeOSRelease {
+            milestone: chromeos::retrieve_crostini_milestone(),
+            board: None,
+            track: None,
+            crostini: true,
+        });
+    }
+    info.wsl = wsl::retrieve();
+    info.container = container::retrieve();
+    info.virtualization = virtualization::retrieve();
+    info.cloud = cloud::retrieve();
+    info.kernel = kernel::retrieve(uname.as_ref());
+    info.architecture = Some(architecture::retrieve(uname.as_ref()));
+    info.libc = libc::retrieve();
+    info.init = Some(init_system::retrieve());
+    info.immutable = immutable::retrieve();
+    info.chroot = chroot::retrieve();
+    info.security = security::retrieve();
+    info.firmware = firmware::retrieve();
+    info.sandbox = sandbox::retrieve();
+    let in_sandbox = info.sandbox.as_ref().is_some_and(|sandbox| sandbox.sandbox != Sandbox::AppImage);
+    let in_tool_container = info.container.as_ref().is_some_and(|container| container.tool.is_some());
+    if in_sandbox || in_tool_container {
+        info.host = host_platform().map(Box::new);
+    }
+    info.package_managers = Some(package_manager::retrieve(info.os_type.family().package_format()));
+    if info.os_type == OSType::Ubuntu {
+        let kernel_release = info.kernel.as_ref().map(|kernel| kernel.release.as_str());
+        info.ubuntu = Some(ubuntu::retrieve(&info.version, kernel_release));
+    }
+    info
+}
+
+fn detect_platform(uname: Option<&uname::Uname>) -> OSInformation {
+    if sw_vers::is_os_x() {
         get_sw_vers()
+    } else if let Some(info) = uname.and_then(uname_release) {
+        info
+    } else if android::is_available() || android::is_termux() {
+        android_release()
+    } else if chromeos::is_available() {
+        chromeos_release()
     } else if lsb_release::is_available() {
         lsb_release()
     } else if utils::file_exists("/etc/os-release") {
@@ -216,3 +849,39 @@ pub fn current_platform() -> OSInformation {
         unknown_os()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn host(module: &str, name: &str) -> OSInformation {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(module).join(name);
+        host_info(sandbox::read_host_os_release(&root).unwrap())
+    }
+
+    #[test]
+    fn resolves_flatpak_host() {
+        let host = host("sandbox", "flatpak");
+        assert_eq!(host.os_type, OSType::Ubuntu);
+        assert_eq!(host.version, "22.04");
+        assert_eq!(host.codename(), Some("jammy"));
+        assert_eq!(host.os_release_id(), Some("ubuntu"));
+    }
+
+    #[test]
+    fn keeps_unlisted_flatpak_hosts() {
+        let host = host("sandbox", "flatpak-fedora");
+        assert_eq!(host.os_type, OSType::Unknown);
+        assert_eq!(host.version, "40");
+        assert_eq!(host.os_release_id(), Some("fedora"));
+    }
+
+    #[test]
+    fn resolves_distrobox_host() {
+        let host = host("container", "distrobox-podman");
+        assert_eq!(host.os_type, OSType::Unknown);
+        assert_eq!(host.version, "40");
+        assert_eq!(host.os_release_id(), Some("fedora"));
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * C library related checks. Nothing is linked or executed, the C library
+ * is identified from its files.
+ */
+use elf;
+use regex::bytes::Regex as BytesRegex;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use utils;
+
+/// A C library implementation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LibC {
+    Glibc,
+    Musl,
+    Bionic,
+    #[cfg_attr(feature = "serde", serde(rename = "uclibc"))]
+    UClibc,
+}
+
+/// Holds information about the system C library
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LibCInfo {
+    pub libc: LibC,
+    /// The version, e.g. `2.36`, if it could be determined
+    pub version: Option<String>,
+    /// The dynamic loader, e.g. `/lib64/ld-linux-x86-64.so.2`
+    pub loader: Option<String>,
+}
+
+impl LibCInfo {
+    /// Returns `true` for glibc of at least the given version
+    pub fn is_glibc_at_least(&self, major: u32, minor: u32) -> bool {
+        self.libc == LibC::Glibc
+            && self.version.as_ref()
+                .and_then(|version| utils::major_minor(version))
+                .is_some_and(|version| version >= (major, minor))
+    }
+}
+
+const LOADER_DIRS: [&str; 4] = ["/lib", "/lib64", "/usr/lib", "/system/bin"];
+
+const LIBRARY_DIRS: [&str; 6] = ["/lib", "/lib64", "/usr/lib", "/usr/lib64", "/lib32", "/usr/lib32"];
+
+pub fn retrieve() -> Option<LibCInfo> {
+    detect(Path::new("/"))
+}
+
+/// Identifies the C library below `root` from the interpreter of
+/// `/bin/sh` or, failing that, from the dynamic loaders installed
+pub fn detect(root: &Path) -> Option<LibCInfo> {
+    let loader = ["/bin/sh", "/system/bin/sh"].iter()
+        .filter_map(|shell| elf::read_interpreter(utils::rooted(root, shell)))
+        .find(|interpreter| parse_loader(interpreter).is_some())
+        .or_else(|| find_loader(root))?;
+    let libc = parse_loader(&loader)?;
+
+    let version = match libc {
+        LibC::Glibc => glibc_version(root, &loader),
+        LibC::Musl => utils::read_file(utils::rooted(root, "/lib/apk/db/installed"))
+            .ok()
+            .and_then(|installed| parse_apk_musl_version(&installed)),
+        LibC::UClibc => library_files(root)
+            .iter()
+            .filter_map(|path| path.rsplit('/').next())
+            .filter_map(|name| parse_library_version(name, "libuClibc-"))
+            .next(),
+        LibC::Bionic => None,
+    };
+
+    Some(LibCInfo { libc, version, loader: Some(loader) })
+}
+
+/// Maps the path of a dynamic loader to the C library it belongs to
+pub fn parse_loader(loader: &str) -> Option<LibC> {
+    let name = loader.rsplit('/').next().unwrap_or(loader);
+    if name.starts_with("ld-musl-") {
+        Some(LibC::Musl)
+    } else if name.starts_with("ld-uClibc") {
+        Some(LibC::UClibc)
+    } else if name.starts_with("linker") && loader.starts_with("/system/") {
+        Some(LibC::Bionic)
+    } else if name.starts_with("ld-linux") || name.starts_with("ld64.so") || name == "ld.so.1" {
+        Some(LibC::Glibc)
+    } else {
+        None
+    }
+}
+
+/// Extracts the version from the banner glibc embeds in `libc.so.6`, e.g.
+/// `GNU C Library (Debian GLIBC 2.36-9) stable release version 2.36.`
+pub fn parse_glibc_banner(bytes: &[u8]) -> Option<String> {
+    let banner_regex = BytesRegex::new(r"GNU C Library[^\x00]*? release version (\d+\.\d+(?:\.\d+)?)").unwrap();
+
+    let captures = banner_regex.captures(bytes)?;
+    String::from_utf8(captures[1].to_vec()).ok()
+}
+
+/// Extracts the musl version from the apk database, which lists
+/// packages as blocks of `P:name` and `V:version` lines
+pub fn parse_apk_musl_version(installed: &str) -> Option<String> {
+    let musl_regex = Regex::new(r"(?m)^P:musl\n(?:[A-Za-z]:.*\n)*?V:(\d+(?:\.\d+)*)").unwrap();
+
+    let captures = musl_regex.captures(installed)?;
+    Some(captures[1].to_owned())
+}
+
+/// Extracts the version from a library file name like `libc-2.28.so` or
+/// `libuClibc-1.0.45.so`
+pub fn parse_library_version(name: &str, prefix: &str) -> Option<String> {
+    if !name.starts_with(prefix) || !name.ends_with(".so") {
+        return None;
+    }
+    let version = &name[prefix.len()..name.len() - ".so".len()];
+    utils::major_minor(version).map(|_| version.to_owned())
+}
+
+fn find_loader(root: &Path) -> Option<String> {
+    LOADER_DIRS.iter()
+        .filter_map(|dir| fs::read_dir(utils::rooted(root, dir)).ok().map(|entries| (dir, entries)))
+        .flat_map(|(dir, entries)| {
+            entries.filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .map(move |name| format!("{}/{}", dir, name))
+        })
+        .find(|path| parse_loader(path).is_some())
+}
+
+/// Lists the file names in the library directories, including the
+/// multiarch directories like `/lib/x86_64-linux-gnu`
+fn library_files(root: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut dirs = LIBRARY_DIRS.iter()
+        .map(|dir| utils::rooted(root, dir))
+        .collect::<Vec<_>>();
+
+    while let Some(dir) = dirs.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let name = match entry.file_name().into_string() {
+                Ok(name) => name,
+                Err(_) => continue
+            };
+            if name.contains("-linux-gnu") {
+                dirs.push(entry.path());
+            } else {
+                files.push(entry.path().to_string_lossy().into_owned());
+            }
+        }
+    }
+    files
+}
+
+/// Resolves a path below `root` through its symbolic links, e.g.
+/// `/lib64/ld-linux-x86-64.so.2` to `/lib/x86_64-linux-gnu/ld-linux-x86-64.so.2`
+fn resolve_links(root: &Path, path: &str) -> String {
+    let mut path = path.to_owned();
+    // Bounded, in case of a symbolic link loop
+    for _ in 0..8 {
+        let target = match fs::read_link(utils::rooted(root, &path)) {
+            Ok(target) => target.to_string_lossy().into_owned(),
+            Err(_) => break
+        };
+        path = if target.starts_with('/') {
+            target
+        } else {
+            let dir = path.rsplit_once('/').map_or("", |(dir, _)| dir);
+            format!("{}/{}", dir, target)
+        };
+    }
+    path
+}
+
+/// Reads the version from the banner in the `.rodata` section of the
+/// `libc.so.6` next to the loader. Only if there is none, the library
+/// directories are searched for `libc.so.6` or a `libc-2.28.so` file name.
+fn glibc_version(root: &Path, loader: &str) -> Option<String> {
+    let loader = resolve_links(root, loader);
+    let loader_dir = loader.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let next_to_loader = utils::rooted(root, &format!("{}/libc.so.6", loader_dir));
+    if let Some(version) = elf::read_section(next_to_loader, ".rodata")
+        .and_then(|rodata| parse_glibc_banner(&rodata))
+    {
+        return Some(version);
+    }
+
+    let files = library_files(root);
+    files.iter()
+        .find(|path| path.ends_with("/libc.so.6"))
+        .and_then(|libc| elf::read_section(libc, ".rodata"))
+        .and_then(|rodata| parse_glibc_banner(&rodata))
+        .or_else(|| {
+            files.iter()
+                .filter_map(|path| path.rsplit('/').next())
+                .filter_map(|name| parse_library_version(name, "libc-"))
+                .next()
+        })
+}
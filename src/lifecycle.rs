@@ -0,0 +1,281 @@
+/*
+ * Release lifecycle data
+ */
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use self::ExtendedSupport::*;
+use utils;
+
+/// A calendar date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub const fn new(year: u32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    /// Returns the current date in UTC
+    pub fn today() -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        Date::from_days(seconds / 86_400)
+    }
+
+    /// Converts days since 1970-01-01 into a date
+    pub fn from_days(days: u64) -> Date {
+        // Howard Hinnant's civil_from_days, restricted to dates after 1970
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date::new(year as u32, month as u32, day as u32)
+    }
+
+    /// Parses an ISO 8601 date like `2024-06-30`
+    pub fn parse(date: &str) -> Option<Date> {
+        let mut parts = date.trim().splitn(3, '-').map(|part| part.parse::<u32>().ok());
+        let year = parts.next()??;
+        let month = parts.next()??;
+        let day = parts.next()??;
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date::new(year, month, day))
+    }
+}
+
+// is_multiple_of() needs Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Dates are serialized in ISO 8601 form, e.g. `"2024-06-30"`
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Date {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Date {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        Date::parse(&date).ok_or_else(|| ::serde::de::Error::custom(format!("invalid date `{}`", date)))
+    }
+}
+
+/// The kind of support offered after standard support ended
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ExtendedSupport {
+    /// Ubuntu Pro Expanded Security Maintenance
+    #[cfg_attr(feature = "serde", serde(rename = "esm"))]
+    ESM,
+    /// Debian Long Term Support
+    #[cfg_attr(feature = "serde", serde(rename = "lts"))]
+    LTS,
+    /// Red Hat Extended Life Cycle Support
+    #[cfg_attr(feature = "serde", serde(rename = "els"))]
+    ELS,
+    /// Security fixes for the previous releases, as Apple and Oracle ship them
+    SecurityUpdates,
+}
+
+/// The support phase a release is in on a given date
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LifecycleStatus {
+    Supported,
+    /// Only security fixes are provided, possibly through a paid program
+    SecurityOnly,
+    #[cfg_attr(feature = "serde", serde(rename = "eol"))]
+    EOL,
+}
+
+/// The support dates of a release. Dates not announced by the vendor yet
+/// are `None`, rolling releases have no dates at all.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lifecycle {
+    pub release: Option<Date>,
+    /// The last day of standard support
+    pub support_end: Option<Date>,
+    pub extended: Option<ExtendedSupport>,
+    /// The last day of extended support
+    pub extended_end: Option<Date>,
+}
+
+impl Lifecycle {
+    /// Returns the support phase the release is in on `date`
+    pub fn status(&self, date: Date) -> LifecycleStatus {
+        match (self.support_end, self.extended, self.extended_end) {
+            (None, _, _) => LifecycleStatus::Supported,
+            (Some(end), _, _) if date <= end => LifecycleStatus::Supported,
+            (_, Some(_), None) => LifecycleStatus::SecurityOnly,
+            (_, Some(_), Some(end)) if date <= end => LifecycleStatus::SecurityOnly,
+            _ => LifecycleStatus::EOL,
+        }
+    }
+}
+
+struct Entry {
+    distro: &'static str,
+    version: &'static str,
+    release: Date,
+    support_end: Option<Date>,
+    extended: Option<(ExtendedSupport, Option<Date>)>,
+}
+
+const fn date(year: u32, month: u32, day: u32) -> Option<Date> {
+    Some(Date::new(year, month, day))
+}
+
+const fn entry(distro: &'static str, version: &'static str, release: Date, support_end: Option<Date>) -> Entry {
+    Entry { distro, version, release, support_end, extended: None }
+}
+
+const fn extended(
+    distro: &'static str,
+    version: &'static str,
+    release: Date,
+    support_end: Option<Date>,
+    program: ExtendedSupport,
+    extended_end: Option<Date>,
+) -> Entry {
+    Entry { distro, version, release, support_end, extended: Some((program, extended_end)) }
+}
+
+const ROLLING: [&str; 7] = ["arch", "manjaro", "kali", "openindiana", "smartos", "haiku", "opensuse-tumbleweed"];
+
+const LIFECYCLES: &[Entry] = &[
+    extended("ubuntu", "14.04", Date::new(2014, 4, 17), date(2019, 4, 30), ESM, date(2024, 4, 30)),
+    extended("ubuntu", "16.04", Date::new(2016, 4, 21), date(2021, 4, 30), ESM, date(2026, 4, 30)),
+    extended("ubuntu", "18.04", Date::new(2018, 4, 26), date(2023, 5, 31), ESM, date(2028, 4, 30)),
+    extended("ubuntu", "20.04", Date::new(2020, 4, 23), date(2025, 5, 31), ESM, date(2030, 4, 30)),
+    extended("ubuntu", "22.04", Date::new(2022, 4, 21), date(2027, 6, 30), ESM, date(2032, 4, 30)),
+    extended("ubuntu", "24.04", Date::new(2024, 4, 25), date(2029, 5, 31), ESM, date(2034, 4, 30)),
+    entry("ubuntu", "23.04", Date::new(2023, 4, 20), date(2024, 1, 25)),
+    entry("ubuntu", "23.10", Date::new(2023, 10, 12), date(2024, 7, 11)),
+    entry("ubuntu", "24.10", Date::new(2024, 10, 10), date(2025, 7, 10)),
+    entry("ubuntu", "25.04", Date::new(2025, 4, 17), date(2026, 1, 15)),
+    entry("ubuntu", "25.10", Date::new(2025, 10, 9), date(2026, 7, 9)),
+    extended("debian", "8", Date::new(2015, 4, 26), date(2018, 6, 17), LTS, date(2020, 6, 30)),
+    extended("debian", "9", Date::new(2017, 6, 17), date(2020, 7, 6), LTS, date(2022, 6, 30)),
+    extended("debian", "10", Date::new(2019, 7, 6), date(2022, 9, 10), LTS, date(2024, 6, 30)),
+    extended("debian", "11", Date::new(2021, 8, 14), date(2024, 8, 14), LTS, date(2026, 8, 31)),
+    extended("debian", "12", Date::new(2023, 6, 10), date(2026, 6, 10), LTS, date(2028, 6, 30)),
+    extended("debian", "13", Date::new(2025, 8, 9), date(2028, 8, 9), LTS, date(2030, 6, 30)),
+    entry("centos", "6", Date::new(2011, 7, 10), date(2020, 11, 30)),
+    entry("centos", "7", Date::new(2014, 7, 7), date(2024, 6, 30)),
+    entry("centos", "8", Date::new(2019, 9, 24), date(2021, 12, 31)),
+    entry("centos", "9", Date::new(2021, 12, 3), date(2027, 5, 31)),
+    entry("centos", "10", Date::new(2024, 12, 12), date(2030, 1, 1)),
+    extended("rhel", "6", Date::new(2010, 11, 10), date(2020, 11, 30), ELS, date(2024, 6, 30)),
+    extended("rhel", "7", Date::new(2014, 6, 10), date(2024, 6, 30), ELS, date(2028, 6, 30)),
+    extended("rhel", "8", Date::new(2019, 5, 7), date(2029, 5, 31), ELS, date(2032, 5, 31)),
+    extended("rhel", "9", Date::new(2022, 5, 18), date(2032, 5, 31), ELS, date(2035, 5, 31)),
+    extended("rhel", "10", Date::new(2025, 5, 20), date(2035, 5, 31), ELS, date(2038, 5, 31)),
+    entry("opensuse-leap", "15.3", Date::new(2021, 6, 2), date(2022, 12, 31)),
+    entry("opensuse-leap", "15.4", Date::new(2022, 6, 8), date(2023, 12, 7)),
+    entry("opensuse-leap", "15.5", Date::new(2023, 6, 7), date(2024, 12, 31)),
+    entry("opensuse-leap", "15.6", Date::new(2024, 6, 12), date(2026, 4, 30)),
+    entry("alpine", "3.16", Date::new(2022, 5, 23), date(2024, 5, 23)),
+    entry("alpine", "3.17", Date::new(2022, 11, 22), date(2024, 11, 22)),
+    entry("alpine", "3.18", Date::new(2023, 5, 9), date(2025, 5, 9)),
+    entry("alpine", "3.19", Date::new(2023, 12, 7), date(2025, 11, 1)),
+    entry("alpine", "3.20", Date::new(2024, 5, 22), date(2026, 4, 1)),
+    entry("alpine", "3.21", Date::new(2024, 12, 5), date(2026, 11, 1)),
+    entry("alpine", "3.22", Date::new(2025, 5, 30), date(2027, 5, 1)),
+    entry("nixos", "23.11", Date::new(2023, 11, 29), date(2024, 6, 30)),
+    entry("nixos", "24.05", Date::new(2024, 5, 31), date(2024, 12, 31)),
+    entry("nixos", "24.11", Date::new(2024, 11, 30), date(2025, 6, 30)),
+    entry("nixos", "25.05", Date::new(2025, 5, 23), date(2025, 12, 31)),
+    entry("freebsd", "13.2", Date::new(2023, 4, 11), date(2024, 6, 30)),
+    entry("freebsd", "13.3", Date::new(2024, 3, 5), date(2024, 12, 31)),
+    entry("freebsd", "13.4", Date::new(2024, 9, 17), date(2025, 6, 30)),
+    entry("freebsd", "13.5", Date::new(2025, 3, 11), date(2026, 4, 30)),
+    entry("freebsd", "14.0", Date::new(2023, 11, 20), date(2024, 9, 30)),
+    entry("freebsd", "14.1", Date::new(2024, 6, 4), date(2025, 3, 31)),
+    entry("freebsd", "14.2", Date::new(2024, 12, 3), date(2025, 9, 30)),
+    entry("freebsd", "14.3", Date::new(2025, 6, 10), date(2026, 6, 30)),
+    entry("openbsd", "7.4", Date::new(2023, 10, 16), date(2024, 10, 8)),
+    entry("openbsd", "7.5", Date::new(2024, 4, 5), date(2025, 4, 28)),
+    entry("openbsd", "7.6", Date::new(2024, 10, 8), date(2025, 10, 22)),
+    entry("openbsd", "7.7", Date::new(2025, 4, 28), None),
+    entry("openbsd", "7.8", Date::new(2025, 10, 22), None),
+    entry("netbsd", "8", Date::new(2018, 7, 17), date(2024, 5, 30)),
+    entry("netbsd", "9", Date::new(2020, 2, 14), None),
+    entry("netbsd", "10", Date::new(2024, 3, 28), None),
+    entry("omnios", "r151038", Date::new(2021, 5, 3), date(2024, 5, 1)),
+    entry("omnios", "r151046", Date::new(2023, 5, 1), date(2026, 5, 1)),
+    entry("omnios", "r151054", Date::new(2025, 5, 5), date(2028, 5, 1)),
+    extended("solaris", "10", Date::new(2005, 1, 31), date(2015, 1, 31), SecurityUpdates, date(2024, 1, 31)),
+    extended("solaris", "11.4", Date::new(2018, 8, 28), date(2031, 11, 30), SecurityUpdates, date(2034, 11, 30)),
+    extended("macos", "10.15", Date::new(2019, 10, 7), date(2020, 11, 12), SecurityUpdates, date(2022, 10, 24)),
+    extended("macos", "11", Date::new(2020, 11, 12), date(2021, 10, 25), SecurityUpdates, date(2023, 9, 26)),
+    extended("macos", "12", Date::new(2021, 10, 25), date(2022, 10, 24), SecurityUpdates, date(2024, 9, 16)),
+    extended("macos", "13", Date::new(2022, 10, 24), date(2023, 9, 26), SecurityUpdates, date(2025, 9, 15)),
+    extended("macos", "14", Date::new(2023, 9, 26), date(2024, 9, 16), SecurityUpdates, None),
+    extended("macos", "15", Date::new(2024, 9, 16), date(2025, 9, 15), SecurityUpdates, None),
+    entry("macos", "26", Date::new(2025, 9, 15), None),
+    entry("android", "11", Date::new(2020, 9, 8), date(2024, 2, 5)),
+    entry("android", "12", Date::new(2021, 10, 4), date(2025, 3, 3)),
+    entry("android", "13", Date::new(2022, 8, 15), None),
+    entry("android", "14", Date::new(2023, 10, 4), None),
+    entry("android", "15", Date::new(2024, 9, 3), None),
+    entry("android", "16", Date::new(2025, 6, 10), None),
+];
+
+/// Looks up the lifecycle of a release. `distro` is the os-release style
+/// ID, e.g. `ubuntu`, `rhel` or `opensuse-leap`. Deepin and DragonFly BSD
+/// publish no support periods, and ChromeOS updates end on a date specific
+/// to each device, so there is no lifecycle for them.
+pub fn lookup(distro: &str, version: &str) -> Option<Lifecycle> {
+    if ROLLING.contains(&distro) {
+        return Some(Lifecycle {
+            release: None,
+            support_end: None,
+            extended: None,
+            extended_end: None,
+        });
+    }
+
+    LIFECYCLES.iter()
+        .find(|entry| entry.distro == distro && utils::is_release(version.trim(), entry.version))
+        .map(|entry| Lifecycle {
+            release: Some(entry.release),
+            support_end: entry.support_end,
+            extended: entry.extended.map(|(program, _)| program),
+            extended_end: entry.extended.and_then(|(_, end)| end),
+        })
+}
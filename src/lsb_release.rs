@@ -3,7 +3,8 @@ use std::process::Command;
 
 pub struct LsbRelease {
     pub distro: Option<String>,
-    pub version: Option<String>
+    pub version: Option<String>,
+    pub codename: Option<String>
 }
 
 pub fn retrieve() -> Option<LsbRelease> {
@@ -16,40 +17,34 @@ pub fn retrieve() -> Option<LsbRelease> {
 }
 
 pub fn is_available() -> bool {
-    match Command::new("lsb_release").output() {
-        Ok(_) => true,
-        Err(_) => false
-    }
+    Command::new("lsb_release").output().is_ok()
 }
 
 pub fn parse(file: String) -> LsbRelease {
     let distrib_regex = Regex::new(r"Distributor ID:\s*(\w+)").unwrap();
     let distrib_release_regex = Regex::new(r"Release:\s*([\w\.]+)").unwrap();
+    let distrib_codename_regex = Regex::new(r"Codename:\s*([\w\-/]+)").unwrap();
 
     let distro = match distrib_regex.captures_iter(&file).next() {
-        Some(m) => {
-            match m.get(1) {
-                Some(distro) => {
-                    Some(distro.as_str().to_owned())
-                },
-                None => None
-            }
-        },
+        Some(m) => m.get(1).map(|distro| distro.as_str().to_owned()),
         None => None
     };
 
     let version = match distrib_release_regex.captures_iter(&file).next() {
-        Some(m) => {
-            match m.get(1) {
-                Some(version) => Some(version.as_str().to_owned()),
-                None => None
-            }
-        },
+        Some(m) => m.get(1).map(|version| version.as_str().to_owned()),
+        None => None
+    };
+
+    let codename = match distrib_codename_regex.captures_iter(&file).next() {
+        Some(m) => m.get(1)
+            .map(|codename| codename.as_str().to_owned())
+            .filter(|codename| codename != "n/a"),
         None => None
     };
 
     LsbRelease {
-        distro: distro,
-        version: version
+        distro,
+        version,
+        codename
     }
 }
@@ -1,31 +1,29 @@
 use regex::Regex;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::Error;
 use utils;
 
 #[derive(Debug, PartialEq)]
 pub struct OSRelease {
     pub distro: Option<String>,
+    pub id: Option<String>,
     pub version: Option<String>,
-}
-
-fn read_file(filename: &str) -> Result<String, Error> {
-    let mut file = File::open(filename)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    Ok(contents)
+    pub codename: Option<String>,
 }
 
 pub fn retrieve() -> Option<OSRelease> {
     if utils::file_exists("/etc/os-release") {
-        if let Ok(release) = read_file("/etc/os-release") {
+        if let Ok(release) = utils::read_file("/etc/os-release") {
+            Some(parse(release))
+        } else {
+            None
+        }
+    } else if utils::file_exists("/usr/lib/os-release") {
+        if let Ok(release) = utils::read_file("/usr/lib/os-release") {
             Some(parse(release))
         } else {
             None
         }
     } else {
-        if let Ok(release) = read_file("/usr/lib/os-release") {
+        if let Ok(release) = utils::read_file("/var/run/os-release") {
             Some(parse(release))
         } else {
             None
@@ -35,27 +33,39 @@ pub fn retrieve() -> Option<OSRelease> {
 
 pub fn parse(file: String) -> OSRelease {
     let distrib_regex = Regex::new(r#"NAME="(\w+)"#).unwrap();
+    let id_regex = Regex::new(r#"(?m)^\s*ID="?([\w\-\.]+)"#).unwrap();
     let version_regex = Regex::new(r#"VERSION_ID="?([\w\.]+)"#).unwrap();
+    let codename_regex = Regex::new(r#"(?:VERSION|UBUNTU)_CODENAME="?([\w\-]+)"#).unwrap();
 
     let distro = match distrib_regex.captures_iter(&file).next() {
-        Some(m) => match m.get(1) {
-            Some(distro) => Some(distro.as_str().to_owned()),
-            None => None,
-        },
+        Some(m) => m.get(1).map(|distro| distro.as_str().to_owned()),
+        None => None,
+    };
+
+    let id = match id_regex.captures_iter(&file).next() {
+        Some(m) => m.get(1).map(|id| id.as_str().to_owned()),
         None => None,
     };
 
     let version = match version_regex.captures_iter(&file).next() {
-        Some(m) => match m.get(1) {
-            Some(version) => Some(version.as_str().to_owned()),
-            None => None,
-        },
+        Some(m) => m.get(1).map(|version| version.as_str().to_owned()),
+        None => None,
+    };
+
+    let codename = match codename_regex.captures_iter(&file).next() {
+        Some(m) => m.get(1).map(|codename| codename.as_str().to_owned()),
         None => None,
     };
 
-    OSRelease { distro, version }
+    OSRelease {
+        distro,
+        id,
+        version,
+        codename,
+    }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
@@ -81,7 +91,9 @@ mod tests {
             parse(sample),
             OSRelease {
                 distro: Some("Ubuntu".to_string()),
+                id: Some("ubuntu".to_string()),
                 version: Some("18.04".to_string()),
+                codename: Some("bionic".to_string()),
             }
         );
     }
@@ -102,7 +114,9 @@ mod tests {
             parse(sample),
             OSRelease {
                 distro: Some("Alpine".to_string()),
+                id: Some("alpine".to_string()),
                 version: Some("3.9.5".to_string()),
+                codename: None,
             }
         );
     }
@@ -123,7 +137,9 @@ mod tests {
             parse(sample),
             OSRelease {
                 distro: Some("Deepin".to_string()),
+                id: Some("Deepin".to_string()),
                 version: Some("20.3".to_string()),
+                codename: None,
             }
         );
     }
@@ -150,7 +166,9 @@ mod tests {
             parse(sample),
             OSRelease {
                 distro: Some("NixOS".to_string()),
+                id: Some("nixos".to_string()),
                 version: Some("21.11".to_string()),
+                codename: Some("porcupine".to_string()),
             }
         );
     }
@@ -174,7 +192,35 @@ mod tests {
             parse(sample),
             OSRelease {
                 distro: Some("Kali".to_string()),
+                id: Some("kali".to_string()),
                 version: Some("2021.4".to_string()),
+                codename: Some("kali-rolling".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_freebsd_13_2_os_release() {
+        let sample = "\
+        NAME=FreeBSD
+        VERSION=\"13.2-RELEASE-p4\"
+        VERSION_ID=\"13.2\"
+        ID=freebsd
+        ANSI_COLOR=\"0;31\"
+        PRETTY_NAME=\"FreeBSD 13.2-RELEASE-p4\"
+        CPE_NAME=\"cpe:/o:freebsd:freebsd:13.2\"
+        HOME_URL=\"https://FreeBSD.org/\"
+        BUG_REPORT_URL=\"https://bugs.FreeBSD.org/\"
+        "
+        .to_string();
+
+        assert_eq!(
+            parse(sample),
+            OSRelease {
+                distro: Some("FreeBSD".to_string()),
+                id: Some("freebsd".to_string()),
+                version: Some("13.2".to_string()),
+                codename: None,
             }
         );
     }
@@ -0,0 +1,228 @@
+/*
+ * Package installation commands
+ */
+use std::collections::HashMap;
+use std::fmt;
+use std::process::Command;
+use package_manager::PackageManager;
+
+/// A command line, ready to be run or printed into a script
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PackageCommand {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl PackageCommand {
+    fn new(program: &str, args: &[&str]) -> PackageCommand {
+        PackageCommand {
+            program: program.to_owned(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    fn with_packages(mut self, packages: Vec<String>) -> PackageCommand {
+        self.args.extend(packages);
+        self
+    }
+
+    /// Builds a `std::process::Command` running this command line
+    pub fn to_command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        command
+    }
+}
+
+impl fmt::Display for PackageCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.program)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+/// Maps logical package names to the names a package manager uses, e.g.
+/// `python3-dev` to `python3-devel` for dnf. Names without an entry are
+/// passed through unchanged.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PackageNames {
+    names: HashMap<String, HashMap<PackageManager, Vec<String>>>,
+}
+
+impl PackageNames {
+    /// Returns an empty table
+    pub fn new() -> PackageNames {
+        PackageNames::default()
+    }
+
+    /// Returns the table shipped with this crate, covering common
+    /// development packages
+    pub fn builtin() -> PackageNames {
+        use package_manager::PackageManager::*;
+
+        let mut names = PackageNames::new();
+        names.insert_for("build-essential", &[Dnf, Yum, Zypper], &["gcc", "gcc-c++", "make"]);
+        names.insert_for("build-essential", &[Pacman, Xbps], &["base-devel"]);
+        names.insert("build-essential", Apk, &["build-base"]);
+
+        names.insert_for("python3-dev", &[Dnf, Yum, Zypper, Xbps], &["python3-devel"]);
+        names.insert_for("python3-dev", &[Pacman, Homebrew], &["python"]);
+        names.insert("python3-dev", Pkg, &["python3"]);
+        names.insert("python3-dev", Emerge, &["dev-lang/python"]);
+
+        names.insert_for("libssl-dev", &[Dnf, Yum, Xbps], &["openssl-devel"]);
+        names.insert("libssl-dev", Zypper, &["libopenssl-devel"]);
+        names.insert_for("libssl-dev", &[Pacman, Homebrew, Pkg], &["openssl"]);
+        names.insert("libssl-dev", Apk, &["openssl-dev"]);
+        names.insert("libssl-dev", Emerge, &["dev-libs/openssl"]);
+
+        names.insert_for("pkg-config", &[Pacman, Apk, Homebrew, Pkg], &["pkgconf"]);
+        names.insert("pkg-config", Dnf, &["pkgconf-pkg-config"]);
+        names.insert("pkg-config", Emerge, &["dev-util/pkgconf"]);
+
+        names.insert("git", Emerge, &["dev-vcs/git"]);
+        names.insert("curl", Emerge, &["net-misc/curl"]);
+        names
+    }
+
+    /// Sets the names `package` has for `manager`, replacing a previous
+    /// entry. An empty list means the package is not needed there.
+    pub fn insert(&mut self, package: &str, manager: PackageManager, names: &[&str]) {
+        self.names.entry(package.to_owned())
+            .or_default()
+            .insert(manager, names.iter().map(|name| name.to_string()).collect());
+    }
+
+    /// Sets the same names for several package managers
+    pub fn insert_for(&mut self, package: &str, managers: &[PackageManager], names: &[&str]) {
+        for &manager in managers {
+            self.insert(package, manager, names);
+        }
+    }
+
+    /// Returns the names `package` has for `manager`
+    pub fn resolve(&self, package: &str, manager: PackageManager) -> Vec<String> {
+        self.names.get(package)
+            .and_then(|managers| managers.get(&manager))
+            .cloned()
+            .unwrap_or_else(|| vec![package.to_owned()])
+    }
+}
+
+/// Turns logical package lists into commands for one package manager
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PackageCommands {
+    pub manager: PackageManager,
+    /// The name table used to translate package names, extend it to add
+    /// or override mappings
+    pub names: PackageNames,
+    /// The Nix channel packages are installed from, `nixpkgs` by default
+    /// and `nixos` on NixOS
+    pub nix_channel: String,
+}
+
+impl PackageCommands {
+    /// Returns the commands for `manager`, using the built-in name table
+    pub fn new(manager: PackageManager) -> PackageCommands {
+        PackageCommands {
+            manager,
+            names: PackageNames::builtin(),
+            nix_channel: "nixpkgs".to_owned(),
+        }
+    }
+
+    fn resolve_all(&self, packages: &[&str]) -> Vec<String> {
+        let mut resolved: Vec<String> = Vec::new();
+        for package in packages {
+            for name in self.names.resolve(package, self.manager) {
+                if !resolved.contains(&name) {
+                    resolved.push(name);
+                }
+            }
+        }
+        resolved
+    }
+
+    /// Returns the non-interactive command installing `packages`, or `None`
+    /// for low-level tools like dpkg and rpm which can't fetch packages and
+    /// if no package is left to install after resolving the names
+    pub fn install(&self, packages: &[&str]) -> Option<PackageCommand> {
+        let command = match self.manager {
+            PackageManager::Apt => PackageCommand::new("apt-get", &["install", "-y"]),
+            PackageManager::Dnf => PackageCommand::new("dnf", &["install", "-y"]),
+            PackageManager::Yum => PackageCommand::new("yum", &["install", "-y"]),
+            PackageManager::Zypper => PackageCommand::new("zypper", &["--non-interactive", "install"]),
+            PackageManager::Pacman => PackageCommand::new("pacman", &["-S", "--noconfirm", "--needed"]),
+            PackageManager::Apk => PackageCommand::new("apk", &["add"]),
+            PackageManager::Xbps => PackageCommand::new("xbps-install", &["-y"]),
+            PackageManager::Emerge => PackageCommand::new("emerge", &["--noreplace"]),
+            PackageManager::Pkg => PackageCommand::new("pkg", &["install", "-y"]),
+            PackageManager::Nix => PackageCommand::new("nix-env", &["-iA"]),
+            PackageManager::Homebrew => PackageCommand::new("brew", &["install"]),
+            PackageManager::Flatpak => PackageCommand::new("flatpak", &["install", "-y"]),
+            PackageManager::Snap => PackageCommand::new("snap", &["install"]),
+            PackageManager::Dpkg | PackageManager::Rpm => return None,
+        };
+        let mut packages = self.resolve_all(packages);
+        if packages.is_empty() {
+            return None;
+        }
+        if self.manager == PackageManager::Nix {
+            packages = packages.into_iter().map(|name| format!("{}.{}", self.nix_channel, name)).collect();
+        }
+        Some(command.with_packages(packages))
+    }
+
+    /// Returns the command refreshing the package index, or `None` if the
+    /// package manager has none. On Arch this upgrades the system as well,
+    /// as refreshing the index alone leads to unsupported partial upgrades.
+    pub fn update(&self) -> Option<PackageCommand> {
+        let command = match self.manager {
+            PackageManager::Apt => PackageCommand::new("apt-get", &["update"]),
+            PackageManager::Dnf => PackageCommand::new("dnf", &["makecache"]),
+            PackageManager::Yum => PackageCommand::new("yum", &["makecache"]),
+            PackageManager::Zypper => PackageCommand::new("zypper", &["--non-interactive", "refresh"]),
+            PackageManager::Pacman => PackageCommand::new("pacman", &["-Syu", "--noconfirm"]),
+            PackageManager::Apk => PackageCommand::new("apk", &["update"]),
+            PackageManager::Xbps => PackageCommand::new("xbps-install", &["-S"]),
+            PackageManager::Emerge => PackageCommand::new("emerge", &["--sync"]),
+            PackageManager::Pkg => PackageCommand::new("pkg", &["update"]),
+            PackageManager::Nix => PackageCommand::new("nix-channel", &["--update"]),
+            PackageManager::Homebrew => PackageCommand::new("brew", &["update"]),
+            PackageManager::Flatpak => PackageCommand::new("flatpak", &["update", "--appstream"]),
+            PackageManager::Dpkg | PackageManager::Rpm | PackageManager::Snap => return None,
+        };
+        Some(command)
+    }
+
+    /// Returns one command per resolved package name, each exiting
+    /// successfully if that package is installed. Not every tool accepts
+    /// several packages at once, e.g. `xbps-query`.
+    pub fn is_installed(&self, packages: &[&str]) -> Vec<PackageCommand> {
+        let command = match self.manager {
+            PackageManager::Apt | PackageManager::Dpkg => PackageCommand::new("dpkg", &["-s"]),
+            PackageManager::Dnf | PackageManager::Yum | PackageManager::Zypper | PackageManager::Rpm => {
+                PackageCommand::new("rpm", &["-q"])
+            }
+            PackageManager::Pacman => PackageCommand::new("pacman", &["-Q"]),
+            PackageManager::Apk => PackageCommand::new("apk", &["info", "-e"]),
+            PackageManager::Xbps => PackageCommand::new("xbps-query", &[]),
+            PackageManager::Emerge => PackageCommand::new("portageq", &["has_version", "/"]),
+            PackageManager::Pkg => PackageCommand::new("pkg", &["info", "-e"]),
+            PackageManager::Nix => PackageCommand::new("nix-env", &["-q"]),
+            PackageManager::Homebrew => PackageCommand::new("brew", &["list", "--versions"]),
+            PackageManager::Flatpak => PackageCommand::new("flatpak", &["info"]),
+            PackageManager::Snap => PackageCommand::new("snap", &["list"]),
+        };
+        self.resolve_all(packages)
+            .into_iter()
+            .map(|name| command.clone().with_packages(vec![name]))
+            .collect()
+    }
+}
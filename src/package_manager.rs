@@ -0,0 +1,153 @@
+/*
+ * Package manager related checks
+ */
+use std::path::Path;
+use utils;
+
+/// A package manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PackageManager {
+    Apt,
+    Dpkg,
+    Dnf,
+    Yum,
+    Zypper,
+    Rpm,
+    Pacman,
+    Apk,
+    Xbps,
+    Emerge,
+    Pkg,
+    Nix,
+    Homebrew,
+    Flatpak,
+    Snap,
+}
+
+/// A native package format
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PackageFormat {
+    Deb,
+    Rpm,
+    /// Arch Linux `.pkg.tar.zst` packages
+    Pacman,
+    Apk,
+    Xbps,
+    /// Gentoo ebuilds
+    Ebuild,
+    /// FreeBSD `pkg` packages
+    Pkg,
+    Nix,
+}
+
+/// Holds the package managers available on the system
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PackageManagerInfo {
+    /// The native package format, e.g. `Deb` on Debian
+    pub format: Option<PackageFormat>,
+    /// The managers for the native format, front-ends first, e.g. `Apt`
+    /// before `Dpkg`
+    pub native: Vec<PackageManager>,
+    /// Further managers, e.g. Flatpak, Snap, Homebrew or an rpm
+    /// installed next to dpkg
+    pub secondary: Vec<PackageManager>,
+}
+
+const BIN_DIRS: [&str; 5] = ["/usr/bin", "/bin", "/usr/sbin", "/sbin", "/usr/local/sbin"];
+
+const HOMEBREW_PATHS: [&str; 3] = [
+    "/opt/homebrew/bin/brew",
+    "/usr/local/bin/brew",
+    "/home/linuxbrew/.linuxbrew/bin/brew",
+];
+
+pub fn retrieve(preferred: Option<PackageFormat>) -> PackageManagerInfo {
+    detect(Path::new("/"), preferred)
+}
+
+/// Looks for package databases below `root`. If databases of several
+/// formats exist, `preferred`, derived from the OS family, decides which
+/// one is native.
+pub fn detect(root: &Path, preferred: Option<PackageFormat>) -> PackageManagerInfo {
+    let exists = |path: &str| utils::file_exists(utils::rooted(root, path));
+    let has_command = |name: &str| {
+        BIN_DIRS.iter().any(|dir| utils::file_exists(utils::rooted(root, dir).join(name)))
+    };
+
+    let mut found: Vec<(PackageFormat, Vec<PackageManager>)> = Vec::new();
+    if exists("/var/lib/dpkg/status") {
+        let mut managers = Vec::new();
+        if has_command("apt-get") {
+            managers.push(PackageManager::Apt);
+        }
+        managers.push(PackageManager::Dpkg);
+        found.push((PackageFormat::Deb, managers));
+    }
+    if exists("/var/lib/rpm") || exists("/usr/lib/sysimage/rpm") {
+        let mut managers = Vec::new();
+        if has_command("dnf") {
+            managers.push(PackageManager::Dnf);
+        }
+        if has_command("yum") {
+            managers.push(PackageManager::Yum);
+        }
+        if has_command("zypper") {
+            managers.push(PackageManager::Zypper);
+        }
+        managers.push(PackageManager::Rpm);
+        found.push((PackageFormat::Rpm, managers));
+    }
+    if exists("/var/lib/pacman/local") {
+        found.push((PackageFormat::Pacman, vec![PackageManager::Pacman]));
+    }
+    if exists("/lib/apk/db/installed") {
+        found.push((PackageFormat::Apk, vec![PackageManager::Apk]));
+    }
+    if exists("/var/db/xbps") {
+        found.push((PackageFormat::Xbps, vec![PackageManager::Xbps]));
+    }
+    if exists("/var/db/pkg/local.sqlite") {
+        found.push((PackageFormat::Pkg, vec![PackageManager::Pkg]));
+    } else if exists("/var/db/pkg") && has_command("emerge") {
+        found.push((PackageFormat::Ebuild, vec![PackageManager::Emerge]));
+    }
+    if exists("/nix/var/nix/db") {
+        found.push((PackageFormat::Nix, vec![PackageManager::Nix]));
+    }
+
+    // Nix is mostly installed next to another package manager, so it only
+    // counts as native if it is the only one or the OS family asks for it
+    let native_index = preferred
+        .and_then(|preferred| found.iter().position(|&(format, _)| format == preferred))
+        .or_else(|| found.iter().position(|&(format, _)| format != PackageFormat::Nix))
+        .or_else(|| found.first().map(|_| 0));
+
+    let mut format = None;
+    let mut native = Vec::new();
+    let mut secondary = Vec::new();
+    for (index, (found_format, managers)) in found.into_iter().enumerate() {
+        if Some(index) == native_index {
+            format = Some(found_format);
+            native = managers;
+        } else {
+            secondary.extend(managers);
+        }
+    }
+
+    if exists("/var/lib/flatpak") || has_command("flatpak") {
+        secondary.push(PackageManager::Flatpak);
+    }
+    if exists("/var/lib/snapd/snaps") || has_command("snap") {
+        secondary.push(PackageManager::Snap);
+    }
+    if HOMEBREW_PATHS.iter().any(|path| exists(path)) {
+        secondary.push(PackageManager::Homebrew);
+    }
+
+    PackageManagerInfo { format, native, secondary }
+}
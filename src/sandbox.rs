@@ -0,0 +1,111 @@
+/*
+ * Application sandbox related checks
+ */
+use std::collections::HashMap;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::path::Path;
+use utils;
+
+/// The sandbox or bundle format an application runs from
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Sandbox {
+    Flatpak,
+    Snap,
+    /// AppImages aren't sandboxed, but bring their own libraries
+    AppImage,
+}
+
+/// Holds information about the sandbox the process runs in
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SandboxInfo {
+    pub sandbox: Sandbox,
+    /// The application ID, e.g. `org.gnome.Builder` or the snap name
+    pub app_id: Option<String>,
+    /// The Flatpak runtime, e.g. `org.freedesktop.Platform/x86_64/23.08`
+    pub runtime: Option<String>,
+    /// The Flatpak version, the snap revision or the AppImage path
+    pub version: Option<String>,
+}
+
+/// Where sandboxes expose the host's os-release
+const HOST_OS_RELEASE: [&str; 5] = [
+    "/run/host/os-release",
+    "/run/host/etc/os-release",
+    "/run/host/usr/lib/os-release",
+    "/var/lib/snapd/hostfs/etc/os-release",
+    "/var/lib/snapd/hostfs/usr/lib/os-release",
+];
+
+pub fn retrieve() -> Option<SandboxInfo> {
+    detect(Path::new("/"), &env::vars_os().collect())
+}
+
+/// Identifies the sandbox from `/.flatpak-info` below `root` and the
+/// `SNAP_*` and `APPIMAGE` variables in `vars`. Values which aren't valid
+/// UTF-8 are converted lossily.
+pub fn detect(root: &Path, vars: &HashMap<OsString, OsString>) -> Option<SandboxInfo> {
+    if let Ok(file) = utils::read_file(utils::rooted(root, "/.flatpak-info")) {
+        return Some(parse_flatpak_info(&file));
+    }
+
+    let var = |name: &str| {
+        vars.get(OsStr::new(name))
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_string_lossy().into_owned())
+    };
+    if var("SNAP").is_some() || var("SNAP_NAME").is_some() {
+        Some(SandboxInfo {
+            sandbox: Sandbox::Snap,
+            app_id: var("SNAP_INSTANCE_NAME").or_else(|| var("SNAP_NAME")),
+            runtime: None,
+            version: var("SNAP_REVISION"),
+        })
+    } else {
+        var("APPIMAGE").map(|path| SandboxInfo {
+            sandbox: Sandbox::AppImage,
+            app_id: Path::new(&path).file_stem().map(|name| name.to_string_lossy().into_owned()),
+            runtime: None,
+            version: Some(path),
+        })
+    }
+}
+
+/// Parses the ini style `/.flatpak-info` file Flatpak places in every
+/// sandbox
+pub fn parse_flatpak_info(file: &str) -> SandboxInfo {
+    let mut section = "";
+    let mut values: HashMap<(String, String), String> = HashMap::new();
+    for line in file.lines().map(str::trim) {
+        if line.starts_with('[') && line.ends_with(']') {
+            section = &line[1..line.len() - 1];
+        } else if let Some(index) = line.find('=') {
+            let key = (section.to_owned(), line[..index].trim().to_owned());
+            values.insert(key, line[index + 1..].trim().to_owned());
+        }
+    }
+    let mut value = |section: &str, key: &str| values.remove(&(section.to_owned(), key.to_owned()));
+
+    SandboxInfo {
+        sandbox: Sandbox::Flatpak,
+        app_id: value("Application", "name").or_else(|| value("Runtime", "name")),
+        runtime: value("Application", "runtime")
+            .map(|runtime| runtime.trim_start_matches("runtime/").to_owned()),
+        version: value("Instance", "flatpak-version"),
+    }
+}
+
+pub fn retrieve_host_os_release() -> Option<String> {
+    read_host_os_release(Path::new("/"))
+}
+
+/// Reads the host's os-release as exposed inside Flatpak, Toolbox and
+/// Distrobox under `/run/host`, or by snapd under its hostfs
+pub fn read_host_os_release(root: &Path) -> Option<String> {
+    HOST_OS_RELEASE.iter()
+        .filter_map(|path| utils::read_file(utils::rooted(root, path)).ok())
+        .next()
+}
@@ -0,0 +1,125 @@
+/*
+ * Security feature related checks
+ */
+use efivars;
+use std::path::Path;
+use utils;
+
+/// The mode SELinux runs or is configured to run in
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum SELinuxMode {
+    Enforcing,
+    Permissive,
+    Disabled,
+}
+
+/// Holds the SELinux state
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SELinuxInfo {
+    /// The current mode
+    pub mode: SELinuxMode,
+    /// The mode set in `/etc/selinux/config`, applied on the next boot
+    pub configured_mode: Option<SELinuxMode>,
+    /// The policy, e.g. `targeted`
+    pub policy: Option<String>,
+}
+
+/// The kernel lockdown mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LockdownMode {
+    /// Lockdown is available, but set to `none`
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
+    Disabled,
+    Integrity,
+    Confidentiality,
+}
+
+/// Holds the state of the kernel's security features
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SecurityInfo {
+    /// `None` if SELinux is neither available nor configured
+    pub selinux: Option<SELinuxInfo>,
+    pub apparmor: bool,
+    /// `true` if the kernel runs in FIPS mode
+    pub fips: bool,
+    /// `None` if the kernel doesn't support lockdown
+    pub lockdown: Option<LockdownMode>,
+    /// `None` on systems which didn't boot via UEFI
+    pub secure_boot: Option<bool>,
+}
+
+/// Returns the security state of the running Linux kernel. The features
+/// checked don't exist on other systems, so they return `None`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn retrieve() -> Option<SecurityInfo> {
+    Some(detect(Path::new("/")))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn retrieve() -> Option<SecurityInfo> {
+    None
+}
+
+/// Reads the security state from the sysfs, procfs and configuration
+/// files below `root`
+pub fn detect(root: &Path) -> SecurityInfo {
+    let read = |path: &str| {
+        utils::read_file(utils::rooted(root, path))
+            .ok()
+            .map(|value| value.trim().to_owned())
+    };
+
+    let configured_selinux = read("/etc/selinux/config")
+        .map(|config| utils::parse_key_values(&config));
+    let configured_mode = configured_selinux.as_ref()
+        .and_then(|config| config.get("SELINUX"))
+        .and_then(|mode| parse_selinux_mode(mode));
+    let mode = match read("/sys/fs/selinux/enforce").as_deref() {
+        Some("1") => Some(SELinuxMode::Enforcing),
+        Some("0") => Some(SELinuxMode::Permissive),
+        _ if configured_mode.is_some() => Some(SELinuxMode::Disabled),
+        _ => None,
+    };
+    let selinux = mode.map(|mode| SELinuxInfo {
+        mode,
+        configured_mode,
+        policy: configured_selinux.and_then(|mut config| config.remove("SELINUXTYPE")),
+    });
+
+    SecurityInfo {
+        selinux,
+        apparmor: read("/sys/module/apparmor/parameters/enabled").as_deref() == Some("Y"),
+        fips: read("/proc/sys/crypto/fips_enabled").as_deref() == Some("1"),
+        lockdown: read("/sys/kernel/security/lockdown").and_then(|lockdown| parse_lockdown(&lockdown)),
+        secure_boot: efivars::read_bool(root, "SecureBoot", efivars::GLOBAL_GUID),
+    }
+}
+
+/// Parses an SELinux mode as written in `/etc/selinux/config`
+pub fn parse_selinux_mode(mode: &str) -> Option<SELinuxMode> {
+    match mode.trim().to_lowercase().as_str() {
+        "enforcing" => Some(SELinuxMode::Enforcing),
+        "permissive" => Some(SELinuxMode::Permissive),
+        "disabled" => Some(SELinuxMode::Disabled),
+        _ => None,
+    }
+}
+
+/// Parses `/sys/kernel/security/lockdown`, which lists all modes and puts
+/// the active one in brackets, e.g. `none [integrity] confidentiality`
+pub fn parse_lockdown(lockdown: &str) -> Option<LockdownMode> {
+    let active = lockdown.split_whitespace()
+        .find(|mode| mode.starts_with('[') && mode.ends_with(']'))?;
+    match active.trim_matches(|c| c == '[' || c == ']') {
+        "none" => Some(LockdownMode::Disabled),
+        "integrity" => Some(LockdownMode::Integrity),
+        "confidentiality" => Some(LockdownMode::Confidentiality),
+        _ => None,
+    }
+}
@@ -3,6 +3,7 @@
  */
 use std::process::Command;
 use regex::Regex;
+use utils;
 
 pub struct SwVers {
     pub product_name: Option<String>,
@@ -10,18 +11,10 @@ pub struct SwVers {
     pub build_version: Option<String>
 }
 
-fn extract_from_regex(stdout: &String, regex: Regex) -> Option<String> {
-    match regex.captures_iter(&stdout).next() {
-        Some(m) => {
-            match m.get(1) {
-                Some(s) => {
-                    Some(s.as_str().to_owned())
-                },
-                None => None
-            }
-        },
-        None => None
-    }
+fn extract_from_regex(stdout: &str, regex: Regex) -> Option<String> {
+    regex.captures_iter(stdout).next()
+        .and_then(|m| m.get(1))
+        .map(|s| s.as_str().trim().to_owned())
 }
 
 pub fn is_os_x() -> bool {
@@ -42,9 +35,9 @@ pub fn retrieve() -> Option<SwVers> {
 }
 
 pub fn parse(version_str: String) -> SwVers {
-    let product_name_regex = Regex::new(r"ProductName:\s([\w\s]+)\n").unwrap();
-    let product_version_regex = Regex::new(r"ProductVersion:\s(\w+\.\w+\.\w+)").unwrap();
-    let build_number_regex = Regex::new(r"BuildVersion:\s(\w+)").unwrap();
+    let product_name_regex = Regex::new(r"ProductName:\s*([^\n]+)").unwrap();
+    let product_version_regex = Regex::new(r"ProductVersion:\s*(\d+(?:\.\d+)*)").unwrap();
+    let build_number_regex = Regex::new(r"BuildVersion:\s*(\w+)").unwrap();
 
     SwVers {
         product_name: extract_from_regex(&version_str, product_name_regex),
@@ -52,3 +45,44 @@ pub fn parse(version_str: String) -> SwVers {
         build_version: extract_from_regex(&version_str, build_number_regex),
     }
 }
+
+/// The name Apple shipped the operating system under
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum MacOSProductName {
+    /// `Mac OS X`, up to 10.7 Lion
+    #[cfg_attr(feature = "serde", serde(rename = "mac-os-x"))]
+    MacOSX,
+    /// `OS X`, from 10.8 Mountain Lion to 10.11 El Capitan
+    #[cfg_attr(feature = "serde", serde(rename = "os-x"))]
+    OSX,
+    /// `macOS`, from 10.12 Sierra onwards
+    #[cfg_attr(feature = "serde", serde(rename = "mac-os"))]
+    MacOS,
+}
+
+/// Holds macOS specific release information
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MacOSRelease {
+    pub product_name: MacOSProductName,
+    pub build_version: Option<String>,
+    pub server: bool,
+}
+
+/// Returns the product name in use for the given version. `sw_vers` kept
+/// printing `Mac OS X` until Big Sur, so its `ProductName` can't be used for this.
+pub fn product_name(version: &str) -> MacOSProductName {
+    match utils::major_minor(version) {
+        Some((10, minor)) if minor <= 7 => MacOSProductName::MacOSX,
+        Some((10, minor)) if minor <= 11 => MacOSProductName::OSX,
+        _ => MacOSProductName::MacOS,
+    }
+}
+
+/// Returns `true` for `Mac OS X Server` style product names
+pub fn is_server(product_name: &str) -> bool {
+    product_name.ends_with("Server")
+}
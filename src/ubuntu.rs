@@ -0,0 +1,225 @@
+/*
+ * Ubuntu specific checks
+ */
+use json;
+use std::path::Path;
+use utils;
+
+/// The kind of an Ubuntu release
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum UbuntuReleaseType {
+    /// A long term support release, e.g. 22.04
+    #[cfg_attr(feature = "serde", serde(rename = "lts"))]
+    LTS,
+    /// A nine month release, e.g. 23.10
+    Interim,
+    /// The release currently under development
+    Development,
+}
+
+/// An official Ubuntu flavour or edition
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum UbuntuFlavour {
+    Desktop,
+    Server,
+    Core,
+    Kubuntu,
+    Xubuntu,
+    Lubuntu,
+    #[cfg_attr(feature = "serde", serde(rename = "ubuntu-mate"))]
+    UbuntuMATE,
+    UbuntuBudgie,
+    UbuntuStudio,
+    UbuntuUnity,
+    UbuntuKylin,
+    UbuntuCinnamon,
+    Edubuntu,
+}
+
+/// The Ubuntu Pro attachment of the system
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UbuntuPro {
+    pub attached: bool,
+    /// The enabled ESM services, e.g. `esm-infra` and `esm-apps`
+    pub esm_services: Vec<String>,
+}
+
+/// Holds Ubuntu specific release information
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UbuntuInfo {
+    pub release_type: UbuntuReleaseType,
+    pub flavour: Option<UbuntuFlavour>,
+    /// `true` if the running kernel is the hardware enablement kernel
+    pub hwe: bool,
+    /// `None` if the Ubuntu Pro client isn't installed
+    pub pro: Option<UbuntuPro>,
+}
+
+// Seed metapackages, flavours first as they may pull in ubuntu-desktop
+const SEEDS: [(&str, UbuntuFlavour); 13] = [
+    ("kubuntu-desktop", UbuntuFlavour::Kubuntu),
+    ("xubuntu-desktop", UbuntuFlavour::Xubuntu),
+    ("lubuntu-desktop", UbuntuFlavour::Lubuntu),
+    ("ubuntu-mate-desktop", UbuntuFlavour::UbuntuMATE),
+    ("ubuntu-budgie-desktop", UbuntuFlavour::UbuntuBudgie),
+    ("ubuntustudio-desktop", UbuntuFlavour::UbuntuStudio),
+    ("ubuntu-unity-desktop", UbuntuFlavour::UbuntuUnity),
+    ("ubuntukylin-desktop", UbuntuFlavour::UbuntuKylin),
+    ("ubuntucinnamon-desktop", UbuntuFlavour::UbuntuCinnamon),
+    ("edubuntu-desktop", UbuntuFlavour::Edubuntu),
+    ("ubuntu-desktop", UbuntuFlavour::Desktop),
+    ("ubuntu-desktop-minimal", UbuntuFlavour::Desktop),
+    ("ubuntu-server", UbuntuFlavour::Server),
+];
+
+pub fn retrieve(version: &str, kernel_release: Option<&str>) -> UbuntuInfo {
+    detect(Path::new("/"), version, kernel_release)
+}
+
+/// Collects Ubuntu specific information from the files below `root`
+pub fn detect(root: &Path, version: &str, kernel_release: Option<&str>) -> UbuntuInfo {
+    let lsb_release = utils::read_file(utils::rooted(root, "/etc/lsb-release"))
+        .map(|file| utils::parse_key_values(&file))
+        .unwrap_or_default();
+    let os_release = utils::read_file(utils::rooted(root, "/etc/os-release"))
+        .map(|file| utils::parse_key_values(&file))
+        .unwrap_or_default();
+    let description = lsb_release.get("DISTRIB_DESCRIPTION").map_or("", String::as_str);
+    let status = utils::read_file(utils::rooted(root, "/var/lib/dpkg/status")).unwrap_or_default();
+    let packages = parse_installed_packages(&status);
+
+    let flavour = if os_release.get("ID").map(String::as_str) == Some("ubuntu-core") {
+        Some(UbuntuFlavour::Core)
+    } else {
+        parse_flavour_description(description).or_else(|| flavour_from_packages(&packages))
+    };
+
+    UbuntuInfo {
+        release_type: release_type(version, description),
+        flavour,
+        hwe: is_hwe(parse_hwe_series(&status), kernel_release),
+        pro: utils::read_file(utils::rooted(root, "/var/lib/ubuntu-advantage/status.json"))
+            .ok()
+            .map(|status| parse_pro_status(&status)),
+    }
+}
+
+/// Classifies a release by its version: LTS releases come out in April of
+/// even years, plus 6.06. Development releases say so in their description.
+pub fn release_type(version: &str, description: &str) -> UbuntuReleaseType {
+    if description.contains("development branch") {
+        return UbuntuReleaseType::Development;
+    }
+
+    match utils::major_minor(version) {
+        Some((6, 6)) => UbuntuReleaseType::LTS,
+        Some((year, 4)) if year % 2 == 0 => UbuntuReleaseType::LTS,
+        _ => UbuntuReleaseType::Interim,
+    }
+}
+
+/// Reads the flavour from a `DISTRIB_DESCRIPTION` like `Kubuntu 22.04 LTS`
+/// or `Ubuntu Core 22`
+pub fn parse_flavour_description(description: &str) -> Option<UbuntuFlavour> {
+    let names = [
+        ("Ubuntu Core", UbuntuFlavour::Core),
+        ("Kubuntu", UbuntuFlavour::Kubuntu),
+        ("Xubuntu", UbuntuFlavour::Xubuntu),
+        ("Lubuntu", UbuntuFlavour::Lubuntu),
+        ("Ubuntu MATE", UbuntuFlavour::UbuntuMATE),
+        ("Ubuntu Budgie", UbuntuFlavour::UbuntuBudgie),
+        ("Ubuntu Studio", UbuntuFlavour::UbuntuStudio),
+        ("Ubuntu Unity", UbuntuFlavour::UbuntuUnity),
+        ("Ubuntu Kylin", UbuntuFlavour::UbuntuKylin),
+        ("Ubuntu Cinnamon", UbuntuFlavour::UbuntuCinnamon),
+        ("Edubuntu", UbuntuFlavour::Edubuntu),
+    ];
+
+    names.iter()
+        .find(|&&(name, _)| description.starts_with(name))
+        .map(|&(_, flavour)| flavour)
+}
+
+/// Returns the paragraphs of the installed packages in
+/// `/var/lib/dpkg/status`
+fn installed_paragraphs(status: &str) -> impl Iterator<Item = &str> {
+    status.split("\n\n").filter(|paragraph| {
+        paragraph.lines().any(|line| line.starts_with("Status:") && line.ends_with(" installed"))
+    })
+}
+
+/// Returns the value of the field `name` in a dpkg status paragraph
+fn field<'a>(paragraph: &'a str, name: &str) -> Option<&'a str> {
+    paragraph.lines()
+        .find(|line| line.starts_with(name) && line[name.len()..].starts_with(':'))
+        .map(|line| line[name.len() + 1..].trim())
+}
+
+/// Returns the names of the installed packages from `/var/lib/dpkg/status`
+pub fn parse_installed_packages(status: &str) -> Vec<String> {
+    installed_paragraphs(status)
+        .filter_map(|paragraph| field(paragraph, "Package"))
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the kernel series tracked by an installed HWE kernel
+/// metapackage, e.g. `(6, 5)` for `linux-generic-hwe-22.04` at version
+/// `6.5.0.14.14~22.04.7`
+pub fn parse_hwe_series(status: &str) -> Option<(u32, u32)> {
+    installed_paragraphs(status)
+        .filter(|paragraph| {
+            field(paragraph, "Package").is_some_and(|package| {
+                package.starts_with("linux-generic-hwe-") || package.starts_with("linux-image-generic-hwe-")
+            })
+        })
+        .filter_map(|paragraph| field(paragraph, "Version"))
+        .find_map(utils::major_minor)
+}
+
+/// Picks the flavour from the installed seed metapackages
+pub fn flavour_from_packages(packages: &[String]) -> Option<UbuntuFlavour> {
+    SEEDS.iter()
+        .find(|&&(seed, _)| packages.iter().any(|package| package == seed))
+        .map(|&(_, flavour)| flavour)
+}
+
+/// Returns `true` if the running kernel belongs to the series of the
+/// installed HWE metapackage. Booting the GA kernel with the metapackage
+/// installed doesn't count, nor do custom, mainline and OEM kernels.
+pub fn is_hwe(hwe_series: Option<(u32, u32)>, kernel_release: Option<&str>) -> bool {
+    hwe_series.is_some() && hwe_series == kernel_release.and_then(utils::major_minor)
+}
+
+/// Parses `/var/lib/ubuntu-advantage/status.json` as written by the Ubuntu
+/// Pro client
+pub fn parse_pro_status(status: &str) -> UbuntuPro {
+    let status = json::parse(status);
+    let attached = status.as_ref()
+        .and_then(|status| status.get("attached"))
+        .and_then(json::Value::as_bool)
+        .unwrap_or(false);
+    let services = status.as_ref()
+        .and_then(|status| status.get("services"))
+        .and_then(json::Value::as_array)
+        .unwrap_or(&[]);
+
+    let esm_services = services.iter()
+        .filter(|service| service.get("status").and_then(json::Value::as_str) == Some("enabled"))
+        .filter_map(|service| service.get("name").and_then(json::Value::as_str))
+        .filter(|name| name.starts_with("esm-"))
+        .map(String::from)
+        .collect();
+
+    UbuntuPro {
+        attached,
+        esm_services,
+    }
+}
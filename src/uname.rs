@@ -0,0 +1,50 @@
+use std::process::Command;
+
+pub struct Uname {
+    pub sysname: Option<String>,
+    pub release: Option<String>,
+    pub machine: Option<String>
+}
+
+pub fn retrieve() -> Option<Uname> {
+    let output = match Command::new("uname").arg("-srm").output() {
+        Ok(o) => o,
+        Err(_) => return None
+    };
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(parse(stdout.to_string()))
+}
+
+/// Parses the output of `uname -srm`, e.g. `FreeBSD 13.2-RELEASE-p4 amd64`
+pub fn parse(output: String) -> Uname {
+    let mut fields = output.split_whitespace().map(|field| field.to_owned());
+
+    Uname {
+        sysname: fields.next(),
+        release: fields.next(),
+        machine: fields.next()
+    }
+}
+
+pub fn retrieve_version() -> Option<String> {
+    let output = match Command::new("uname").arg("-v").output() {
+        Ok(o) => o,
+        Err(_) => return None
+    };
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(stdout.trim().to_owned())
+}
+
+/// Extracts the Haiku revision from `uname -v`, e.g. `hrev57937` from
+/// `hrev57937 Apr 15 2024 07:06:05`
+pub fn parse_haiku_revision(version: &str) -> Option<String> {
+    version.split_whitespace()
+        .find(|field| field.starts_with("hrev"))
+        .map(|revision| revision.to_owned())
+}
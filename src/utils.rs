@@ -1,6 +1,10 @@
+use std::collections::HashMap;
 use std::fs;
+use std::fs::File;
 use std::convert::AsRef;
-use std::path::Path;
+use std::io::prelude::*;
+use std::io::Error;
+use std::path::{Path, PathBuf};
 
 pub fn file_exists<P: AsRef<Path>>(path: P) -> bool {
     let metadata = fs::metadata(path);
@@ -9,4 +13,60 @@ pub fn file_exists<P: AsRef<Path>>(path: P) -> bool {
         Ok(md) => md.is_dir() || md.is_file(),
         Err(_) => false
     }
-}
\ No newline at end of file
+}
+
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, Error> {
+    let mut file = File::open(path)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+/// Splits a dotted version into its numeric major and minor part, treating
+/// a missing minor version as `0`
+pub fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    let major = match parts.next() {
+        Some(Some(major)) => major,
+        _ => return None
+    };
+    let minor = parts.next().and_then(|minor| minor).unwrap_or(0);
+    Some((major, minor))
+}
+
+/// Returns `true` if `version` is `release` or a point release of it,
+/// e.g. `22.04.3` for `22.04` but not `10` for `1`
+pub fn is_release(version: &str, release: &str) -> bool {
+    version.starts_with(release)
+        && !version[release.len()..].starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Parses `KEY=value` lines as found in os-release and similar files.
+/// Quotes around values are removed, blank lines and comments are skipped.
+pub fn parse_key_values(contents: &str) -> HashMap<String, String> {
+    contents.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+            Some((key.to_owned(), unquote(value).to_owned()))
+        })
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in &['"', '\''] {
+        if value.len() >= 2 && value.starts_with(*quote) && value.ends_with(*quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+/// Resolves an absolute path like `/proc/self/cgroup` below `root`, so
+/// checks can run against a fixture tree instead of the live system
+pub fn rooted(root: &Path, path: &str) -> PathBuf {
+    root.join(path.trim_start_matches('/'))
+}
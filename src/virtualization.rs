@@ -0,0 +1,117 @@
+/*
+ * Hypervisor related checks
+ */
+use std::path::Path;
+use utils;
+
+/// The hypervisor the system runs on, similar to `systemd-detect-virt`
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Virtualization {
+    /// Not virtualized
+    #[cfg_attr(feature = "serde", serde(rename = "bare-metal"))]
+    BareMetal,
+    #[cfg_attr(feature = "serde", serde(rename = "kvm"))]
+    KVM,
+    #[cfg_attr(feature = "serde", serde(rename = "qemu"))]
+    QEMU,
+    #[cfg_attr(feature = "serde", serde(rename = "vmware"))]
+    VMware,
+    HyperV,
+    Xen,
+    VirtualBox,
+    Parallels,
+    Bhyve,
+    /// Virtualized, but the hypervisor could not be identified
+    Other,
+    /// No information to tell, e.g. on systems without sysfs
+    Unknown,
+}
+
+const DMI_FILES: [&str; 4] = [
+    "/sys/class/dmi/id/sys_vendor",
+    "/sys/class/dmi/id/product_name",
+    "/sys/class/dmi/id/board_vendor",
+    "/sys/class/dmi/id/bios_vendor",
+];
+
+pub fn retrieve() -> Virtualization {
+    detect(Path::new("/"))
+}
+
+/// Classifies the hypervisor from the DMI strings, `/sys/hypervisor/type`
+/// and the CPU flags found below `root`
+pub fn detect(root: &Path) -> Virtualization {
+    let dmi = DMI_FILES.iter()
+        .filter_map(|path| utils::read_file(utils::rooted(root, path)).ok())
+        .collect::<Vec<_>>();
+    match dmi.iter().filter_map(|value| parse_dmi(value)).next() {
+        // QEMU reports itself in DMI whether or not it is accelerated by KVM
+        Some(Virtualization::QEMU) if has_kvm_clock(root) => return Virtualization::KVM,
+        Some(virtualization) => return virtualization,
+        None => {}
+    }
+
+    if let Ok(hypervisor) = utils::read_file(utils::rooted(root, "/sys/hypervisor/type")) {
+        if hypervisor.trim() == "xen" {
+            return Virtualization::Xen;
+        }
+    }
+
+    match utils::read_file(utils::rooted(root, "/proc/cpuinfo")) {
+        Ok(ref cpuinfo) if has_hypervisor_flag(cpuinfo) => Virtualization::Other,
+        Ok(_) => Virtualization::BareMetal,
+        Err(_) if !dmi.is_empty() => Virtualization::BareMetal,
+        Err(_) => Virtualization::Unknown,
+    }
+}
+
+/// Maps a DMI vendor or product string to the hypervisor that sets it
+pub fn parse_dmi(value: &str) -> Option<Virtualization> {
+    let value = value.trim();
+    let virtualization = if value.starts_with("KVM")
+        || value.starts_with("OpenStack")
+        || value.starts_with("KubeVirt")
+        || value.starts_with("Amazon EC2")
+        || value.starts_with("Google Compute Engine")
+    {
+        Virtualization::KVM
+    } else if value.starts_with("QEMU") || value.starts_with("Bochs") {
+        Virtualization::QEMU
+    } else if value.starts_with("VMware") || value.starts_with("VMW") {
+        Virtualization::VMware
+    } else if value.starts_with("innotek GmbH") || value.starts_with("VirtualBox") {
+        Virtualization::VirtualBox
+    } else if value.starts_with("Xen") {
+        Virtualization::Xen
+    } else if value.starts_with("Parallels") {
+        Virtualization::Parallels
+    } else if value.starts_with("BHYVE") {
+        Virtualization::Bhyve
+    } else if value == "Virtual Machine" {
+        // Hyper-V sets Microsoft Corporation as the vendor, just like
+        // Surface hardware does, so only the product name identifies it
+        Virtualization::HyperV
+    } else {
+        return None;
+    };
+    Some(virtualization)
+}
+
+fn has_kvm_clock(root: &Path) -> bool {
+    let path = "/sys/devices/system/clocksource/clocksource0/available_clocksource";
+    match utils::read_file(utils::rooted(root, path)) {
+        Ok(clocksources) => clocksources.split_whitespace().any(|clocksource| clocksource == "kvm-clock"),
+        Err(_) => false
+    }
+}
+
+/// Returns `true` if the CPU flags in `/proc/cpuinfo` contain the
+/// `hypervisor` bit, which is set when running under any hypervisor
+pub fn has_hypervisor_flag(cpuinfo: &str) -> bool {
+    cpuinfo.lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+}
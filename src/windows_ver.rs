@@ -18,13 +18,8 @@ pub fn parse(output: String) -> WindowsVer {
     let version_regex = Regex::new(r"^Microsoft Windows \[Version\s(\d+\.\d+\.\d+)\]$").unwrap();
 
     let version = match version_regex.captures_iter(&output).next() {
-        Some(m) => {
-            match m.get(1) {
-                Some(version) => Some(version.as_str().to_owned()),
-                None => None
-            }
-        },
+        Some(m) => m.get(1).map(|version| version.as_str().to_owned()),
         None => None
     };
-    WindowsVer { version: version }
+    WindowsVer { version }
 }
@@ -0,0 +1,60 @@
+/*
+ * Windows Subsystem for Linux related checks
+ */
+use std::env;
+use utils;
+
+/// The WSL generation. WSL1 translates Linux system calls, WSL2 runs a
+/// real Linux kernel in a lightweight VM.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum WSLGeneration {
+    #[cfg_attr(feature = "serde", serde(rename = "wsl1"))]
+    WSL1,
+    #[cfg_attr(feature = "serde", serde(rename = "wsl2"))]
+    WSL2,
+}
+
+/// Holds information about the WSL environment the system runs in
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WSLInfo {
+    pub generation: WSLGeneration,
+    /// The name of the WSL distribution, e.g. `Ubuntu-22.04`
+    pub distro_name: Option<String>,
+}
+
+pub fn retrieve() -> Option<WSLInfo> {
+    let kernel = utils::read_file("/proc/sys/kernel/osrelease")
+        .or_else(|_| utils::read_file("/proc/version"))
+        .ok()
+        .and_then(|release| parse_kernel_release(&release));
+    let interop = utils::file_exists("/proc/sys/fs/binfmt_misc/WSLInterop")
+        || utils::file_exists("/proc/sys/fs/binfmt_misc/WSLInterop-late");
+    let distro_name = env::var("WSL_DISTRO_NAME").ok().filter(|name| !name.is_empty());
+
+    let generation = match kernel {
+        Some(generation) => generation,
+        // A custom WSL2 kernel doesn't carry the Microsoft suffix
+        None if interop || distro_name.is_some() => WSLGeneration::WSL2,
+        None => return None,
+    };
+
+    Some(WSLInfo { generation, distro_name })
+}
+
+/// Determines the WSL generation from the kernel release found in
+/// `/proc/sys/kernel/osrelease` or `/proc/version`. WSL1 reports e.g.
+/// `4.4.0-19041-Microsoft`, WSL2 `5.15.133.1-microsoft-standard-WSL2`.
+pub fn parse_kernel_release(release: &str) -> Option<WSLGeneration> {
+    if release.contains("WSL2") || release.contains("microsoft-standard") {
+        Some(WSLGeneration::WSL2)
+    } else if release.contains("Microsoft") {
+        Some(WSLGeneration::WSL1)
+    } else if release.contains("microsoft") {
+        Some(WSLGeneration::WSL2)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+extern crate regex;
+#[path = "../src/android.rs"]
+mod android;
+#[path = "../src/utils.rs"]
+mod utils;
+
+fn build_prop_file() -> String {
+    "
+# begin common build properties
+# autogenerated by build/make/tools/buildinfo_common.sh
+ro.build.version.incremental=11228894
+ro.build.version.sdk=34
+ro.build.version.preview_sdk=0
+ro.build.version.codename=REL
+ro.build.version.release=14
+ro.build.version.security_patch=2024-03-05
+ro.product.manufacturer=Google
+ro.product.model=Pixel 7
+# end common build properties
+"
+    .to_string()
+}
+
+fn getprop_file() -> String {
+    "[ro.build.version.release]: [13]
+[ro.build.version.release_or_codename]: [13]
+[ro.build.version.sdk]: [33]
+[ro.build.version.security_patch]: [2023-11-01]
+[ro.product.manufacturer]: [samsung]
+[ro.product.model]: [SM-G991B]
+"
+    .to_string()
+}
+
+#[test]
+pub fn parses_build_prop_release() {
+    let build_prop = android::parse(build_prop_file());
+    assert_eq!(build_prop.release, Some("14".to_string()));
+    assert_eq!(build_prop.sdk, Some("34".to_string()));
+}
+
+#[test]
+pub fn parses_build_prop_manufacturer_and_patch() {
+    let build_prop = android::parse(build_prop_file());
+    assert_eq!(build_prop.manufacturer, Some("Google".to_string()));
+    assert_eq!(build_prop.security_patch, Some("2024-03-05".to_string()));
+}
+
+#[test]
+pub fn parses_getprop_output() {
+    let build_prop = android::parse_getprop(getprop_file());
+    assert_eq!(build_prop.release, Some("13".to_string()));
+    assert_eq!(build_prop.sdk, Some("33".to_string()));
+    assert_eq!(build_prop.manufacturer, Some("samsung".to_string()));
+    assert_eq!(build_prop.security_patch, Some("2023-11-01".to_string()));
+}
+
+#[test]
+pub fn detects_termux_prefix() {
+    assert!(android::is_termux_prefix("/data/data/com.termux/files/usr"));
+    assert!(android::is_termux_prefix("/data/data/com.termux/files/usr/"));
+    assert!(!android::is_termux_prefix("/usr"));
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/architecture.rs"]
+mod architecture;
+#[path = "../src/elf.rs"]
+mod elf;
+#[path = "../src/uname.rs"]
+mod uname;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use architecture::Architecture;
+use common::fixture;
+
+#[test]
+pub fn detects_armhf_userland_on_64bit_pi_kernel() {
+    let info = architecture::detect(&fixture("architecture", "pi-armhf"), Some("aarch64".to_string()));
+    assert_eq!(info.kernel, Some(Architecture::Aarch64));
+    assert_eq!(info.userland, Some(Architecture::Arm));
+    assert_eq!(info.dpkg_architecture, Some("armhf".to_string()));
+    assert!(info.is_32bit_userland_on_64bit_kernel());
+}
+
+#[test]
+pub fn detects_arm64_userland_on_pi() {
+    let info = architecture::detect(&fixture("architecture", "pi-arm64"), Some("aarch64".to_string()));
+    assert_eq!(info.userland, Some(Architecture::Aarch64));
+    assert!(!info.is_32bit_userland_on_64bit_kernel());
+}
+
+#[test]
+pub fn reads_userland_from_elf_header_without_dpkg() {
+    let info = architecture::detect(&fixture("architecture", "alpine-x86_64"), Some("x86_64".to_string()));
+    assert_eq!(info.userland, Some(Architecture::X86_64));
+    assert_eq!(info.dpkg_architecture, None);
+}
+
+#[test]
+pub fn detects_32bit_x86_userland_on_64bit_kernel() {
+    let info = architecture::detect(&fixture("architecture", "i686-userland"), Some("x86_64".to_string()));
+    assert_eq!(info.userland, Some(Architecture::X86));
+    assert!(info.is_32bit_userland_on_64bit_kernel());
+}
+
+#[test]
+pub fn normalizes_architecture_names() {
+    assert_eq!(Architecture::from_name("amd64"), Architecture::X86_64);
+    assert_eq!(Architecture::from_name("x86_64"), Architecture::X86_64);
+    assert_eq!(Architecture::from_name("arm64"), Architecture::Aarch64);
+    assert_eq!(Architecture::from_name("aarch64"), Architecture::Aarch64);
+    assert_eq!(Architecture::from_name("armv7l"), Architecture::Arm);
+    assert_eq!(Architecture::from_name("ppc64el"), Architecture::PowerPC64);
+    assert_eq!(Architecture::from_name("sparc64"), Architecture::Other("sparc64".to_string()));
+}
+
+#[test]
+pub fn parses_native_dpkg_architecture() {
+    assert_eq!(architecture::parse_dpkg_arch("armhf\narm64\n"), Some("armhf".to_string()));
+    assert_eq!(architecture::parse_dpkg_arch(""), None);
+}
+
+#[test]
+pub fn ignores_program_headers_out_of_bounds() {
+    // A 64-bit little endian header with the maximum phoff, phentsize and phnum
+    let mut bytes = vec![0; 64];
+    bytes[..6].copy_from_slice(b"\x7fELF\x02\x01");
+    bytes[32..40].copy_from_slice(&u64::MAX.to_le_bytes());
+    bytes[54..58].copy_from_slice(&[0xff; 4]);
+    assert_eq!(elf::parse_interpreter(&bytes), None);
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+extern crate regex;
+#[path = "../src/bsd.rs"]
+mod bsd;
+#[path = "../src/utils.rs"]
+mod utils;
+
+fn freebsd_version_file() -> String {
+    "13.2-RELEASE-p3
+13.2-RELEASE-p4
+"
+    .to_string()
+}
+
+fn freebsd_os_release_file() -> String {
+    "NAME=FreeBSD
+VERSION=\"13.2-RELEASE-p4\"
+VERSION_ID=\"13.2\"
+ID=freebsd
+ANSI_COLOR=\"0;31\"
+PRETTY_NAME=\"FreeBSD 13.2-RELEASE-p4\"
+CPE_NAME=\"cpe:/o:freebsd:freebsd:13.2\"
+HOME_URL=\"https://FreeBSD.org/\"
+BUG_REPORT_URL=\"https://bugs.FreeBSD.org/\"
+"
+    .to_string()
+}
+
+#[test]
+pub fn parses_freebsd_kernel_version() {
+    let info = bsd::parse_freebsd_version(freebsd_version_file());
+    assert_eq!(info.kernel, Some("13.2-RELEASE-p3".to_string()));
+}
+
+#[test]
+pub fn parses_freebsd_userland_version() {
+    let info = bsd::parse_freebsd_version(freebsd_version_file());
+    assert_eq!(info.userland, Some("13.2-RELEASE-p4".to_string()));
+}
+
+#[test]
+pub fn parses_freebsd_os_release_version() {
+    let version = bsd::parse_os_release_version(&freebsd_os_release_file());
+    assert_eq!(version, Some("13.2-RELEASE-p4".to_string()));
+}
+
+#[test]
+pub fn parses_patch_level() {
+    let version = bsd::parse_version("13.2-RELEASE-p4").unwrap();
+    assert_eq!(version.version, "13.2");
+    assert_eq!(version.branch, Some("RELEASE".to_string()));
+    assert_eq!(version.patch_level, Some(4));
+}
+
+#[test]
+pub fn parses_version_without_patch_level() {
+    let version = bsd::parse_version("6.4-RELEASE").unwrap();
+    assert_eq!(version.version, "6.4");
+    assert_eq!(version.branch, Some("RELEASE".to_string()));
+    assert_eq!(version.patch_level, None);
+}
+
+#[test]
+pub fn parses_plain_version() {
+    let version = bsd::parse_version("7.4").unwrap();
+    assert_eq!(version.version, "7.4");
+    assert_eq!(version.branch, None);
+    assert_eq!(version.patch_level, None);
+}
+
+#[test]
+pub fn parses_netbsd_release_candidate() {
+    let version = bsd::parse_version("10.0_RC1").unwrap();
+    assert_eq!(version.version, "10.0");
+    assert_eq!(version.branch, Some("RC1".to_string()));
+}
+
+#[test]
+pub fn rejects_non_version() {
+    assert_eq!(bsd::parse_version("unknown"), None);
+}
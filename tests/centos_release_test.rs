@@ -1,3 +1,4 @@
+#![allow(dead_code)]
 extern crate regex;
 #[path="../src/rhel_release.rs"]
 mod rhel_release;
@@ -18,4 +19,9 @@ pub fn test_parses_distribution() {
 pub fn test_parses_version() {
     let parse_results = rhel_release::parse(file());
     assert_eq!(parse_results.version, Some("7.3.1611".to_string()));
-}
\ No newline at end of file
+}
+#[test]
+pub fn test_parses_codename() {
+    let parse_results = rhel_release::parse(file());
+    assert_eq!(parse_results.codename, Some("Core".to_string()));
+}
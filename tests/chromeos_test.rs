@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/chromeos.rs"]
+mod chromeos;
+#[path = "../src/utils.rs"]
+mod utils;
+
+fn lsb_release_file() -> String {
+    "CHROMEOS_AUSERVER=https://tools.google.com/service/update2
+CHROMEOS_BOARD_APPID={9A3BE5D2-C3DC-4AE6-9943-E2C113895DC5}
+CHROMEOS_CANARY_APPID={90F229CE-83E2-4FAF-8479-E368A34938B1}
+CHROMEOS_DEVSERVER=
+CHROMEOS_RELEASE_APPID={9A3BE5D2-C3DC-4AE6-9943-E2C113895DC5}
+CHROMEOS_RELEASE_BOARD=octopus-signed-mp-v43keys
+CHROMEOS_RELEASE_BRANCH_NUMBER=72
+CHROMEOS_RELEASE_BUILDER_PATH=octopus-release/R117-15572.72.0
+CHROMEOS_RELEASE_BUILD_NUMBER=15572
+CHROMEOS_RELEASE_BUILD_TYPE=Official Build
+CHROMEOS_RELEASE_CHROME_MILESTONE=117
+CHROMEOS_RELEASE_DESCRIPTION=15572.72.0 (Official Build) stable-channel octopus
+CHROMEOS_RELEASE_KEYSET=mp-v43
+CHROMEOS_RELEASE_NAME=Chrome OS
+CHROMEOS_RELEASE_PATCH_NUMBER=0
+CHROMEOS_RELEASE_TRACK=stable-channel
+CHROMEOS_RELEASE_UNIBUILD=1
+CHROMEOS_RELEASE_VERSION=15572.72.0
+DEVICETYPE=CHROMEBOOK
+GOOGLE_RELEASE=15572.72.0
+"
+    .to_string()
+}
+
+#[test]
+pub fn parses_chromeos_version() {
+    let release = chromeos::parse(lsb_release_file());
+    assert_eq!(release.version, Some("15572.72.0".to_string()));
+}
+
+#[test]
+pub fn parses_chromeos_milestone() {
+    let release = chromeos::parse(lsb_release_file());
+    assert_eq!(release.milestone, Some("117".to_string()));
+}
+
+#[test]
+pub fn parses_chromeos_board_and_track() {
+    let release = chromeos::parse(lsb_release_file());
+    assert_eq!(release.board, Some("octopus-signed-mp-v43keys".to_string()));
+    assert_eq!(release.track, Some("stable-channel".to_string()));
+}
+
+#[test]
+pub fn parses_crostini_milestone() {
+    assert_eq!(chromeos::parse_milestone("117\n"), Some(117));
+    assert_eq!(chromeos::parse_milestone(""), None);
+}
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+#[path = "../src/chroot.rs"]
+mod chroot;
+#[path = "common/mod.rs"]
+mod common;
+
+use common::fixture;
+use std::env;
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::process;
+
+// Builds a tree linking proc/1/root to `init_root`, the root directory of
+// its PID 1. The links loop, so they are created here instead of being
+// shipped as fixtures.
+#[cfg(unix)]
+fn tree(name: &str, init_root: &Path) -> PathBuf {
+    let root = env::temp_dir().join(format!("os_type-chroot-{}-{}", process::id(), name));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("proc/1")).unwrap();
+    std::os::unix::fs::symlink(init_root, root.join("proc/1/root")).unwrap();
+    root
+}
+
+#[cfg(unix)]
+#[test]
+pub fn same_root_is_no_chroot() {
+    let root = tree("host", Path::new("../.."));
+    assert_eq!(chroot::detect(&root, &root.join("proc/1/root")), Some(false));
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+pub fn different_root_is_a_chroot() {
+    let host = tree("jail-host", Path::new("../.."));
+    let root = tree("jail", &host);
+    assert_eq!(chroot::detect(&root, &root.join("proc/1/root")), Some(true));
+    fs::remove_dir_all(&root).unwrap();
+    fs::remove_dir_all(&host).unwrap();
+}
+
+#[test]
+pub fn unknown_without_access_to_init_root() {
+    assert_eq!(chroot::detect(&env::temp_dir(), &fixture("chroot", "missing")), None);
+}
@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/cloud.rs"]
+mod cloud;
+#[path = "../src/json.rs"]
+mod json;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use cloud::CloudProvider;
+use common::fixture;
+
+fn provider(name: &str) -> Option<CloudProvider> {
+    cloud::detect(&fixture("cloud", name)).map(|info| info.provider)
+}
+
+#[test]
+pub fn detects_aws_nitro() {
+    let info = cloud::detect(&fixture("cloud", "aws-nitro")).unwrap();
+    assert_eq!(info.provider, CloudProvider::AWS);
+    assert!(info.nitro);
+}
+
+#[test]
+pub fn detects_aws_xen() {
+    let info = cloud::detect(&fixture("cloud", "aws-xen")).unwrap();
+    assert_eq!(info.provider, CloudProvider::AWS);
+    assert!(!info.nitro);
+}
+
+#[test]
+pub fn detects_gce() {
+    assert_eq!(provider("gce"), Some(CloudProvider::GCE));
+}
+
+#[test]
+pub fn detects_azure() {
+    assert_eq!(provider("azure"), Some(CloudProvider::Azure));
+}
+
+#[test]
+pub fn detects_oracle_cloud() {
+    assert_eq!(provider("oracle"), Some(CloudProvider::OracleCloud));
+}
+
+#[test]
+pub fn detects_digitalocean() {
+    assert_eq!(provider("digitalocean"), Some(CloudProvider::DigitalOcean));
+}
+
+#[test]
+pub fn detects_hetzner() {
+    assert_eq!(provider("hetzner"), Some(CloudProvider::Hetzner));
+}
+
+#[test]
+pub fn detects_alibaba() {
+    assert_eq!(provider("alibaba"), Some(CloudProvider::Alibaba));
+}
+
+#[test]
+pub fn falls_back_to_cloud_init_instance_data() {
+    let info = cloud::detect(&fixture("cloud", "cloud-init")).unwrap();
+    assert_eq!(info.provider, CloudProvider::AWS);
+    assert_eq!(info.region, Some("eu-central-1".to_string()));
+}
+
+#[test]
+pub fn reads_only_top_level_instance_data() {
+    let data = cloud::parse_instance_data(r#"{
+        "ds": {"meta_data": {"cloud_name": "nocloud", "region": "us-east-1"}},
+        "v1": {"cloud_name": "gce", "region": "europe-west3"}
+    }"#);
+    assert_eq!(data.cloud_name, Some("gce".to_string()));
+    assert_eq!(data.region, Some("europe-west3".to_string()));
+
+    let data = cloud::parse_instance_data(r#"{"v1": {"cloud-name": "aws", "region": ""}}"#);
+    assert_eq!(data.cloud_name, Some("aws".to_string()));
+    assert_eq!(data.region, None);
+    assert_eq!(cloud::parse_instance_data("{").cloud_name, None);
+}
+
+#[test]
+pub fn detects_no_provider_on_premises() {
+    assert_eq!(provider("onprem"), None);
+}
+
+#[test]
+pub fn maps_cloud_init_names() {
+    assert_eq!(cloud::parse_cloud_name("aliyun"), Some(CloudProvider::Alibaba));
+    assert_eq!(cloud::parse_cloud_name("nocloud"), None);
+}
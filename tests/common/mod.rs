@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// Returns the path of a fixture file or tree below `tests/fixtures/<module>`
+pub fn fixture(module: &str, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(module)
+        .join(name)
+}
@@ -0,0 +1,125 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/container.rs"]
+mod container;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use container::{CGroupVersion, ContainerRuntime, ContainerTool};
+use common::fixture;
+
+#[test]
+pub fn detects_docker() {
+    let info = container::detect(&fixture("container", "docker")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Docker);
+    assert_eq!(info.cgroup_version, Some(CGroupVersion::V1));
+    assert!(!info.kubernetes);
+}
+
+#[test]
+pub fn detects_podman() {
+    let info = container::detect(&fixture("container", "podman")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Podman);
+    assert_eq!(info.cgroup_version, Some(CGroupVersion::V2));
+}
+
+#[test]
+pub fn parses_podman_containerenv() {
+    let containerenv = container::detect(&fixture("container", "podman")).unwrap().containerenv.unwrap();
+    assert_eq!(containerenv.engine, Some("podman-4.6.1".to_string()));
+    assert_eq!(containerenv.name, Some("web".to_string()));
+    assert_eq!(containerenv.image, Some("docker.io/library/debian:bookworm".to_string()));
+    assert!(containerenv.rootless);
+}
+
+#[test]
+pub fn detects_crio_despite_containerenv() {
+    let info = container::detect(&fixture("container", "crio")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::CRIO);
+    assert!(info.kubernetes);
+}
+
+#[test]
+pub fn detects_lxc_from_pid1_environment() {
+    let info = container::detect(&fixture("container", "lxc")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::LXC);
+}
+
+#[test]
+pub fn detects_systemd_nspawn() {
+    let info = container::detect(&fixture("container", "nspawn")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::SystemdNspawn);
+    assert_eq!(info.cgroup_version, None);
+}
+
+#[test]
+pub fn detects_kubernetes() {
+    let info = container::detect(&fixture("container", "kubernetes")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Containerd);
+    assert!(info.kubernetes);
+}
+
+#[test]
+pub fn detects_no_container_on_host() {
+    assert_eq!(container::detect(&fixture("container", "host")), None);
+}
+
+#[test]
+pub fn parses_cgroup_v1_signatures() {
+    let cgroup = "4:devices:/lxc/build01\n1:name=systemd:/lxc/build01\n";
+    assert_eq!(container::parse_cgroup_runtime(cgroup), Some(ContainerRuntime::LXC));
+    assert_eq!(container::parse_cgroup_version(cgroup), Some(CGroupVersion::V1));
+}
+
+#[test]
+pub fn parses_cgroup_v2_signatures() {
+    let cgroup = "0::/system.slice/docker-4f3c4c8cf4de.scope\n";
+    assert_eq!(container::parse_cgroup_runtime(cgroup), Some(ContainerRuntime::Docker));
+    assert_eq!(container::parse_cgroup_version(cgroup), Some(CGroupVersion::V2));
+}
+
+#[test]
+pub fn parses_unknown_runtime_name() {
+    assert_eq!(
+        container::parse_runtime_name("oci\n"),
+        Some(ContainerRuntime::Other("oci".to_string()))
+    );
+    assert_eq!(container::parse_runtime_name(""), None);
+}
+
+#[test]
+pub fn detects_toolbox() {
+    let info = container::detect(&fixture("container", "toolbox")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Podman);
+    assert_eq!(info.tool, Some(ContainerTool::Toolbox));
+    assert_eq!(info.containerenv.unwrap().name, Some("fedora-toolbox-39".to_string()));
+}
+
+#[test]
+pub fn detects_distrobox() {
+    let info = container::detect(&fixture("container", "distrobox")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Docker);
+    assert_eq!(info.tool, Some(ContainerTool::Distrobox));
+}
+
+#[test]
+pub fn ignores_toolbox_names_without_marker() {
+    let info = container::detect(&fixture("container", "toolbox-image")).unwrap();
+    assert_eq!(info.tool, None);
+}
+
+#[test]
+pub fn detects_distrobox_on_podman() {
+    let info = container::detect(&fixture("container", "distrobox-podman")).unwrap();
+    assert_eq!(info.runtime, ContainerRuntime::Podman);
+    assert_eq!(info.tool, Some(ContainerTool::Distrobox));
+}
+
+#[test]
+pub fn plain_containers_have_no_tool() {
+    assert_eq!(container::detect(&fixture("container", "podman")).unwrap().tool, None);
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/efivars.rs"]
+mod efivars;
+#[path = "../src/firmware.rs"]
+mod firmware;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use firmware::{BootMode, Bootloader};
+use common::fixture;
+
+#[test]
+pub fn detects_systemd_boot_with_secure_boot() {
+    let info = firmware::detect(&fixture("firmware", "systemd-boot"));
+    assert_eq!(info.boot_mode, BootMode::UEFI);
+    assert_eq!(info.platform_bits, Some(64));
+    assert!(!info.mixed_mode);
+    assert_eq!(info.secure_boot, Some(true));
+    assert_eq!(info.setup_mode, Some(false));
+    assert_eq!(info.bootloader, Some(Bootloader::SystemdBoot));
+    assert_eq!(info.bootloader_version, Some("254.5-1-arch".to_string()));
+}
+
+#[test]
+pub fn detects_grub_on_uefi_in_setup_mode() {
+    let info = firmware::detect(&fixture("firmware", "grub-uefi"));
+    assert_eq!(info.boot_mode, BootMode::UEFI);
+    assert_eq!(info.secure_boot, Some(false));
+    assert_eq!(info.setup_mode, Some(true));
+    assert_eq!(info.bootloader, Some(Bootloader::Grub));
+    assert_eq!(info.bootloader_version, None);
+}
+
+#[test]
+pub fn detects_32bit_uefi_on_64bit_cpu() {
+    let info = firmware::detect(&fixture("firmware", "mixed"));
+    assert_eq!(info.boot_mode, BootMode::UEFI);
+    assert_eq!(info.platform_bits, Some(32));
+    assert!(info.mixed_mode);
+    assert_eq!(info.setup_mode, None);
+    assert_eq!(info.bootloader, None);
+}
+
+#[test]
+pub fn detects_legacy_bios() {
+    let info = firmware::detect(&fixture("firmware", "bios"));
+    assert_eq!(info.boot_mode, BootMode::BIOS);
+    assert_eq!(info.platform_bits, None);
+    assert!(!info.mixed_mode);
+    assert_eq!(info.secure_boot, None);
+    assert_eq!(info.bootloader, Some(Bootloader::Grub));
+}
+
+#[test]
+pub fn reports_unknown_boot_mode_without_firmware_tables() {
+    let info = firmware::detect(&fixture("firmware", "container"));
+    assert_eq!(info.boot_mode, BootMode::Unknown);
+    assert_eq!(firmware::detect(&fixture("firmware", "missing")).boot_mode, BootMode::Unknown);
+}
+
+#[test]
+pub fn parses_loader_info() {
+    assert_eq!(
+        firmware::parse_loader_info("GRUB 2.12"),
+        (Bootloader::Grub, Some("2.12".to_string()))
+    );
+    assert_eq!(
+        firmware::parse_loader_info("rEFInd"),
+        (Bootloader::Other("rEFInd".to_string()), None)
+    );
+}
+
+#[test]
+pub fn detects_long_mode_flag() {
+    assert!(firmware::has_long_mode("flags\t\t: fpu lm nx\n"));
+    assert!(!firmware::has_long_mode("flags\t\t: fpu lahf_lm nx\n"));
+}
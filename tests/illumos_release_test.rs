@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+extern crate regex;
+#[path = "../src/illumos_release.rs"]
+mod illumos_release;
+#[path = "../src/utils.rs"]
+mod utils;
+
+fn omnios_file() -> String {
+    "  OmniOS v11 r151046l
+  Copyright (c) 2012-2017 OmniTI Computer Consulting, Inc.
+  Copyright (c) 2017-2023 OmniOS Community Edition (OmniOSce) Association.
+  All rights reserved. Use is subject to licence terms.
+"
+    .to_string()
+}
+
+fn openindiana_file() -> String {
+    "             OpenIndiana Hipster 2023.10 (powered by illumos)
+        OpenIndiana Project, part of The Illumos Foundation (C) 2010-2023
+                        Use is subject to license terms.
+                           Assembled 30 October 2023
+"
+    .to_string()
+}
+
+fn smartos_file() -> String {
+    "                       SmartOS 20230209T002311Z x86_64
+              Copyright 2010 Sun Microsystems, Inc.  All Rights Reserved.
+              Copyright 2015 Joyent, Inc.  All Rights Reserved.
+                        Use is subject to license terms.
+"
+    .to_string()
+}
+
+fn solaris_file() -> String {
+    "                             Oracle Solaris 11.4 X86
+  Copyright (c) 1983, 2018, Oracle and/or its affiliates.  All rights reserved.
+                            Assembled 16 August 2018
+"
+    .to_string()
+}
+
+fn omnios_os_release_file() -> String {
+    "NAME=\"OmniOS\"
+PRETTY_NAME=\"OmniOS Community Edition v11 r151046l\"
+CPE_NAME=\"cpe:/o:omniosce:omnios:11:151046:12\"
+ID=omnios
+VERSION=r151046l
+VERSION_ID=r151046l
+BUILD_ID=151046.12.2023.05.31
+HOME_URL=\"https://omnios.org/\"
+SUPPORT_URL=\"https://omnios.org/\"
+BUG_REPORT_URL=\"https://github.com/omniosorg/omnios-build/issues/new\"
+"
+    .to_string()
+}
+
+#[test]
+pub fn parses_omnios_release() {
+    let release = illumos_release::parse(omnios_file());
+    assert_eq!(release.distro, Some("OmniOS".to_string()));
+    assert_eq!(release.version, Some("r151046l".to_string()));
+}
+
+#[test]
+pub fn parses_openindiana_release() {
+    let release = illumos_release::parse(openindiana_file());
+    assert_eq!(release.distro, Some("OpenIndiana".to_string()));
+    assert_eq!(release.version, Some("2023.10".to_string()));
+}
+
+#[test]
+pub fn parses_smartos_release() {
+    let release = illumos_release::parse(smartos_file());
+    assert_eq!(release.distro, Some("SmartOS".to_string()));
+    assert_eq!(release.version, Some("20230209T002311Z".to_string()));
+}
+
+#[test]
+pub fn parses_solaris_release() {
+    let release = illumos_release::parse(solaris_file());
+    assert_eq!(release.distro, Some("Solaris".to_string()));
+    assert_eq!(release.version, Some("11.4".to_string()));
+}
+
+#[test]
+pub fn parses_omnios_os_release() {
+    let release = illumos_release::parse_os_release(omnios_os_release_file());
+    assert_eq!(release.distro, Some("OmniOS".to_string()));
+    assert_eq!(release.version, Some("r151046l".to_string()));
+}
+
+#[test]
+pub fn ignores_unknown_os_release() {
+    let release = illumos_release::parse_os_release("ID=ubuntu\nVERSION_ID=\"22.04\"\n".to_string());
+    assert_eq!(release.distro, None);
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/immutable.rs"]
+mod immutable;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use immutable::ImmutableOS;
+use common::fixture;
+
+#[test]
+pub fn detects_silverblue() {
+    let info = immutable::detect(&fixture("immutable", "silverblue")).unwrap();
+    assert_eq!(info.os, ImmutableOS::FedoraAtomic);
+    assert_eq!(info.variant, Some("silverblue".to_string()));
+    assert_eq!(info.image_version, Some("39.20240112.0".to_string()));
+    assert_eq!(info.channel, None);
+    assert!(info.ostree);
+}
+
+#[test]
+pub fn detects_fedora_coreos_with_stream() {
+    let info = immutable::detect(&fixture("immutable", "fcos")).unwrap();
+    assert_eq!(info.os, ImmutableOS::FedoraCoreOS);
+    assert_eq!(info.image_version, Some("39.20240112.3.0".to_string()));
+    assert_eq!(info.channel, Some("stable".to_string()));
+}
+
+#[test]
+pub fn detects_flatcar_with_local_channel() {
+    let info = immutable::detect(&fixture("immutable", "flatcar")).unwrap();
+    assert_eq!(info.os, ImmutableOS::Flatcar);
+    assert_eq!(info.image_version, Some("3760.2.0".to_string()));
+    assert_eq!(info.channel, Some("beta".to_string()));
+    assert!(!info.ostree);
+}
+
+#[test]
+pub fn detects_bottlerocket_variant() {
+    let info = immutable::detect(&fixture("immutable", "bottlerocket")).unwrap();
+    assert_eq!(info.os, ImmutableOS::Bottlerocket);
+    assert_eq!(info.variant, Some("aws-k8s-1.28".to_string()));
+    assert_eq!(info.image_version, Some("1.19.0".to_string()));
+}
+
+#[test]
+pub fn detects_talos() {
+    let info = immutable::detect(&fixture("immutable", "talos")).unwrap();
+    assert_eq!(info.os, ImmutableOS::Talos);
+    assert_eq!(info.image_version, Some("v1.6.1".to_string()));
+}
+
+#[test]
+pub fn detects_other_ostree_systems() {
+    let info = immutable::detect(&fixture("immutable", "ostree")).unwrap();
+    assert_eq!(info.os, ImmutableOS::OSTree);
+    assert_eq!(info.image_version, Some("5.1".to_string()));
+}
+
+#[test]
+pub fn ignores_mutable_systems() {
+    assert_eq!(immutable::detect(&fixture("immutable", "debian")), None);
+}
+
+#[test]
+pub fn parses_origin_streams() {
+    assert_eq!(
+        immutable::parse_origin("[origin]\nrefspec=fedora:fedora/x86_64/coreos/testing\n"),
+        Some("testing".to_string())
+    );
+    assert_eq!(
+        immutable::parse_origin("[origin]\ncontainer-image-reference=ostree-remote-registry:fedora:quay.io/fedora/fedora-coreos:next\n"),
+        Some("next".to_string())
+    );
+    assert_eq!(immutable::parse_origin("[origin]\n"), None);
+}
@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+extern crate regex;
+#[path = "../src/init_system.rs"]
+mod init_system;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use init_system::InitSystem;
+use common::fixture;
+
+#[test]
+pub fn detects_systemd_with_version() {
+    let info = init_system::detect(&fixture("init_system", "systemd"));
+    assert_eq!(info.init, InitSystem::Systemd);
+    assert_eq!(info.version, Some("252".to_string()));
+    assert_eq!(info.pid1, Some("systemd".to_string()));
+}
+
+#[cfg(unix)]
+#[test]
+pub fn detects_systemd_from_pid1_executable() {
+    let info = init_system::detect(&fixture("init_system", "exe-only"));
+    assert_eq!(info.init, InitSystem::Systemd);
+    assert_eq!(info.pid1, Some("systemd".to_string()));
+}
+
+#[test]
+pub fn detects_openrc() {
+    assert_eq!(init_system::detect(&fixture("init_system", "openrc")).init, InitSystem::OpenRC);
+}
+
+#[test]
+pub fn detects_runit() {
+    assert_eq!(init_system::detect(&fixture("init_system", "runit")).init, InitSystem::Runit);
+}
+
+#[test]
+pub fn detects_s6() {
+    assert_eq!(init_system::detect(&fixture("init_system", "s6")).init, InitSystem::S6);
+}
+
+#[test]
+pub fn detects_sysvinit() {
+    assert_eq!(init_system::detect(&fixture("init_system", "sysvinit")).init, InitSystem::SysVinit);
+}
+
+#[test]
+pub fn detects_launchd() {
+    assert_eq!(init_system::detect(&fixture("init_system", "launchd")).init, InitSystem::Launchd);
+}
+
+#[test]
+pub fn detects_no_init_in_containers() {
+    let info = init_system::detect(&fixture("init_system", "container"));
+    assert_eq!(info.init, InitSystem::NoInit);
+    assert_eq!(info.pid1, Some("tini".to_string()));
+}
+
+#[test]
+pub fn detects_nothing_without_proc() {
+    assert_eq!(init_system::detect(&fixture("init_system", "missing")).init, InitSystem::Unknown);
+}
+
+#[test]
+pub fn parses_systemd_shared_library_names() {
+    assert_eq!(init_system::parse_systemd_shared_version("libsystemd-shared-252.so"), Some("252".to_string()));
+    assert_eq!(
+        init_system::parse_systemd_shared_version("libsystemd-shared-255.4-1.fc40.so"),
+        Some("255".to_string())
+    );
+    assert_eq!(init_system::parse_systemd_shared_version("libsystemd.so.0"), None);
+}
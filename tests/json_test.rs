@@ -0,0 +1,72 @@
+#![allow(dead_code)]
+#[path = "../src/json.rs"]
+mod json;
+
+use json::Value;
+
+#[test]
+pub fn parses_literals() {
+    assert_eq!(json::parse("true"), Some(Value::Bool(true)));
+    assert_eq!(json::parse(" false "), Some(Value::Bool(false)));
+    assert_eq!(json::parse("null"), Some(Value::Null));
+}
+
+#[test]
+pub fn parses_numbers() {
+    assert_eq!(json::parse("42"), Some(Value::Number(42.0)));
+    assert_eq!(json::parse("-2.5"), Some(Value::Number(-2.5)));
+    assert_eq!(json::parse("1e3"), Some(Value::Number(1000.0)));
+    assert_eq!(json::parse("6.02E-1"), Some(Value::Number(0.602)));
+}
+
+#[test]
+pub fn parses_string_escapes() {
+    assert_eq!(
+        json::parse(r#""a\"b\\c\/d\n\t""#),
+        Some(Value::String("a\"b\\c/d\n\t".to_string()))
+    );
+    assert_eq!(json::parse(r#""\u00e9t\u00C9""#), Some(Value::String("étÉ".to_string())));
+    // Lone surrogates can't be represented and are replaced
+    assert_eq!(json::parse(r#""\ud83d""#), Some(Value::String("\u{fffd}".to_string())));
+}
+
+#[test]
+pub fn parses_nested_arrays_and_objects() {
+    let value = json::parse(r#"{"a": [1, [true, {"b": "c"}], []], "d": {}}"#).unwrap();
+    let a = value.get("a").and_then(Value::as_array).unwrap();
+    assert_eq!(a.len(), 3);
+    assert_eq!(a[0], Value::Number(1.0));
+    let inner = a[1].as_array().unwrap();
+    assert_eq!(inner[0].as_bool(), Some(true));
+    assert_eq!(inner[1].get("b").and_then(Value::as_str), Some("c"));
+    assert_eq!(a[2], Value::Array(vec![]));
+    assert_eq!(value.get("d"), Some(&Value::Object(vec![])));
+    assert_eq!(value.get("b"), None);
+}
+
+#[test]
+pub fn keeps_member_order() {
+    let value = json::parse(r#"{"z": 1, "a": 2}"#).unwrap();
+    assert_eq!(
+        value,
+        Value::Object(vec![("z".to_string(), Value::Number(1.0)), ("a".to_string(), Value::Number(2.0))])
+    );
+}
+
+#[test]
+pub fn rejects_malformed_input() {
+    for input in &[
+        "", "{", "[1,]", "[1 2]", r#"{"a" 1}"#, r#"{"a": 1,}"#, r#"{a: 1}"#, r#""open"#,
+        r#""\u12""#, "tru", "nul", "1 2", "-", "{}}",
+    ] {
+        assert_eq!(json::parse(input), None, "{}", input);
+    }
+}
+
+#[test]
+pub fn rejects_deep_nesting() {
+    let deep = format!("{}{}", "[".repeat(100), "]".repeat(100));
+    assert_eq!(json::parse(&deep), None);
+    let shallow = format!("{}{}", "[".repeat(10), "]".repeat(10));
+    assert!(json::parse(&shallow).is_some());
+}
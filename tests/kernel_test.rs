@@ -0,0 +1,101 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+extern crate regex;
+#[path = "../src/kernel.rs"]
+mod kernel;
+#[path = "../src/uname.rs"]
+mod uname;
+#[path = "../src/utils.rs"]
+mod utils;
+
+use kernel::KernelFlavour;
+
+fn ubuntu_proc_version() -> String {
+    "Linux version 5.15.0-91-generic (buildd@lcy02-amd64-045) (gcc (Ubuntu 11.4.0-1ubuntu1~22.04) 11.4.0, GNU ld (GNU Binutils for Ubuntu) 2.38) #101-Ubuntu SMP Tue Nov 14 13:30:08 UTC 2023
+"
+    .to_string()
+}
+
+fn custom_proc_version() -> String {
+    "Linux version 6.7.0-rc3+ (jan@workstation) (gcc (GCC) 13.2.1 20230801, GNU ld (GNU Binutils) 2.41.0) #4 SMP PREEMPT_DYNAMIC Sat Dec  2 11:04:51 CET 2023
+"
+    .to_string()
+}
+
+#[test]
+pub fn parses_ubuntu_kernel_version() {
+    let version = kernel::parse_version("5.15.0-91-generic").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (5, 15, 0));
+    assert_eq!(version.local, Some("-91-generic".to_string()));
+}
+
+#[test]
+pub fn parses_kernel_version_without_patch() {
+    let version = kernel::parse_version("6.7-rc3").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (6, 7, 0));
+    assert_eq!(version.local, Some("-rc3".to_string()));
+}
+
+#[test]
+pub fn parses_plain_kernel_version() {
+    let version = kernel::parse_version("6.6.7\n").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (6, 6, 7));
+    assert_eq!(version.local, None);
+}
+
+#[test]
+pub fn classifies_generic_flavour() {
+    assert_eq!(kernel::parse_flavour("5.15.0-91-generic", "#101-Ubuntu SMP"), KernelFlavour::Generic);
+    assert_eq!(kernel::parse_flavour("4.18.0-513.el8.x86_64", "#1 SMP"), KernelFlavour::Generic);
+}
+
+#[test]
+pub fn classifies_lowlatency_flavour() {
+    assert_eq!(kernel::parse_flavour("5.15.0-91-lowlatency", "#101-Ubuntu SMP PREEMPT"), KernelFlavour::LowLatency);
+}
+
+#[test]
+pub fn classifies_realtime_flavour() {
+    assert_eq!(kernel::parse_flavour("6.1.0-17-rt-amd64", "#1 SMP PREEMPT_RT Debian"), KernelFlavour::RealTime);
+    assert_eq!(kernel::parse_flavour("6.6.8", "#1 SMP PREEMPT_RT Tue Dec 19 2023"), KernelFlavour::RealTime);
+    assert_eq!(kernel::parse_flavour("6.6.7-rt18", "#1 SMP"), KernelFlavour::RealTime);
+    assert_eq!(kernel::parse_flavour("4.18.0-513.rt7.313.el8_9.x86_64", "#1 SMP"), KernelFlavour::RealTime);
+}
+
+#[test]
+pub fn rt_prefixed_suffixes_are_not_realtime() {
+    assert_eq!(kernel::parse_flavour("6.1.0-rtl8821", "#1 SMP"), KernelFlavour::Generic);
+    assert_eq!(kernel::parse_flavour("5.10.0-rtx", "#1 SMP"), KernelFlavour::Generic);
+}
+
+#[test]
+pub fn classifies_cloud_flavours() {
+    assert_eq!(kernel::parse_flavour("6.2.0-1017-aws", "#17~22.04.1-Ubuntu SMP"), KernelFlavour::AWS);
+    assert_eq!(kernel::parse_flavour("6.2.0-1019-azure", "#19~22.04.1-Ubuntu SMP"), KernelFlavour::Azure);
+}
+
+#[test]
+pub fn classifies_wsl_flavour() {
+    assert_eq!(kernel::parse_flavour("5.15.133.1-microsoft-standard-WSL2", "#1 SMP"), KernelFlavour::WSL);
+}
+
+#[test]
+pub fn classifies_grsec_flavour() {
+    assert_eq!(kernel::parse_flavour("4.9.24-grsec", "#1 SMP"), KernelFlavour::Grsecurity);
+}
+
+#[test]
+pub fn detects_distro_kernels() {
+    assert!(kernel::is_distro_kernel("5.15.0-91-generic", &ubuntu_proc_version()));
+    assert!(kernel::is_distro_kernel("6.1.0-17-amd64", ""));
+    assert!(kernel::is_distro_kernel("4.18.0-513.el8.x86_64", ""));
+    assert!(kernel::is_distro_kernel("6.6.7-arch1-1", ""));
+}
+
+#[test]
+pub fn detects_custom_kernels() {
+    assert!(!kernel::is_distro_kernel("6.7.0-rc3+", &custom_proc_version()));
+    assert!(!kernel::is_distro_kernel("6.6.7", ""));
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+extern crate regex;
+#[path = "../src/elf.rs"]
+mod elf;
+#[path = "../src/libc.rs"]
+mod libc;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use libc::LibC;
+use common::fixture;
+
+#[test]
+pub fn detects_glibc_from_shell_interpreter() {
+    let info = libc::detect(&fixture("libc", "glibc")).unwrap();
+    assert_eq!(info.libc, LibC::Glibc);
+    assert_eq!(info.loader, Some("/lib64/ld-linux-x86-64.so.2".to_string()));
+}
+
+#[test]
+pub fn reads_glibc_version_from_banner() {
+    let info = libc::detect(&fixture("libc", "glibc")).unwrap();
+    assert_eq!(info.version, Some("2.36".to_string()));
+    assert!(info.is_glibc_at_least(2, 28));
+    assert!(!info.is_glibc_at_least(2, 38));
+}
+
+#[test]
+pub fn detects_glibc_from_loader_and_file_name() {
+    let info = libc::detect(&fixture("libc", "glibc-armhf")).unwrap();
+    assert_eq!(info.libc, LibC::Glibc);
+    assert_eq!(info.loader, Some("/lib/ld-linux-armhf.so.3".to_string()));
+    assert_eq!(info.version, Some("2.28".to_string()));
+}
+
+#[test]
+pub fn detects_musl() {
+    let info = libc::detect(&fixture("libc", "musl")).unwrap();
+    assert_eq!(info.libc, LibC::Musl);
+    assert_eq!(info.version, Some("1.2.4".to_string()));
+    assert!(!info.is_glibc_at_least(2, 28));
+}
+
+#[test]
+pub fn detects_uclibc() {
+    let info = libc::detect(&fixture("libc", "uclibc")).unwrap();
+    assert_eq!(info.libc, LibC::UClibc);
+    assert_eq!(info.version, Some("1.0.45".to_string()));
+}
+
+#[test]
+pub fn detects_bionic() {
+    let info = libc::detect(&fixture("libc", "bionic")).unwrap();
+    assert_eq!(info.libc, LibC::Bionic);
+    assert_eq!(info.loader, Some("/system/bin/linker".to_string()));
+}
+
+#[test]
+pub fn detects_nothing_without_loader() {
+    assert_eq!(libc::detect(&fixture("libc", "missing")), None);
+}
+
+#[test]
+pub fn maps_loader_names() {
+    assert_eq!(libc::parse_loader("/lib/ld-linux-aarch64.so.1"), Some(LibC::Glibc));
+    assert_eq!(libc::parse_loader("/lib/ld64.so.2"), Some(LibC::Glibc));
+    assert_eq!(libc::parse_loader("/lib/ld-musl-aarch64.so.1"), Some(LibC::Musl));
+    assert_eq!(libc::parse_loader("/usr/libexec/ld.so"), None);
+}
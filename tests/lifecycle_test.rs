@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/lifecycle.rs"]
+mod lifecycle;
+#[path = "../src/utils.rs"]
+mod utils;
+
+use lifecycle::{Date, ExtendedSupport, LifecycleStatus};
+
+fn status(distro: &str, version: &str, date: &str) -> Option<LifecycleStatus> {
+    lifecycle::lookup(distro, version).map(|lifecycle| lifecycle.status(Date::parse(date).unwrap()))
+}
+
+#[test]
+pub fn flags_eol_releases() {
+    assert_eq!(status("centos", "7.9.2009", "2024-07-01"), Some(LifecycleStatus::EOL));
+    assert_eq!(status("debian", "10", "2024-07-01"), Some(LifecycleStatus::EOL));
+    assert_eq!(status("ubuntu", "23.10", "2024-08-01"), Some(LifecycleStatus::EOL));
+}
+
+#[test]
+pub fn reports_extended_support() {
+    let bionic = lifecycle::lookup("ubuntu", "18.04").unwrap();
+    assert_eq!(bionic.release, Some(Date::new(2018, 4, 26)));
+    assert_eq!(bionic.extended, Some(ExtendedSupport::ESM));
+    assert_eq!(bionic.status(Date::new(2023, 5, 31)), LifecycleStatus::Supported);
+    assert_eq!(bionic.status(Date::new(2023, 6, 1)), LifecycleStatus::SecurityOnly);
+    assert_eq!(bionic.status(Date::new(2028, 5, 1)), LifecycleStatus::EOL);
+
+    assert_eq!(status("debian", "11.9", "2025-01-01"), Some(LifecycleStatus::SecurityOnly));
+    assert_eq!(status("rhel", "7.9", "2025-01-01"), Some(LifecycleStatus::SecurityOnly));
+}
+
+#[test]
+pub fn treats_open_extended_support_as_security_only() {
+    assert_eq!(status("macos", "14.2.1", "2024-01-01"), Some(LifecycleStatus::Supported));
+    assert_eq!(status("macos", "14.2.1", "2030-01-01"), Some(LifecycleStatus::SecurityOnly));
+}
+
+#[test]
+pub fn matches_point_releases_only() {
+    assert!(lifecycle::lookup("freebsd", "13.2-RELEASE-p4").is_some());
+    assert!(lifecycle::lookup("freebsd", "13.20").is_none());
+    assert!(lifecycle::lookup("debian", "1").is_none());
+    assert!(lifecycle::lookup("omnios", "r151046l").is_some());
+}
+
+#[test]
+pub fn keeps_rolling_releases_supported() {
+    let arch = lifecycle::lookup("arch", "rolling").unwrap();
+    assert_eq!(arch.release, None);
+    assert_eq!(arch.status(Date::new(2099, 1, 1)), LifecycleStatus::Supported);
+}
+
+#[test]
+pub fn returns_none_for_unknown_releases() {
+    assert_eq!(lifecycle::lookup("ubuntu", "4.10"), None);
+    assert_eq!(lifecycle::lookup("plan9", "4"), None);
+}
+
+#[test]
+pub fn has_no_lifecycle_for_unpublished_support_periods() {
+    assert_eq!(lifecycle::lookup("deepin", "20.9"), None);
+    assert_eq!(lifecycle::lookup("dragonfly", "6.4"), None);
+    assert_eq!(lifecycle::lookup("chromeos", "15633.69.0"), None);
+}
+
+#[test]
+pub fn converts_days_to_dates() {
+    assert_eq!(Date::from_days(0), Date::new(1970, 1, 1));
+    assert_eq!(Date::from_days(11_017), Date::new(2000, 3, 1));
+    assert_eq!(Date::from_days(19_723), Date::new(2024, 1, 1));
+    assert!(Date::today() > Date::new(2024, 1, 1));
+}
+
+#[test]
+pub fn parses_dates() {
+    assert_eq!(Date::parse("2024-06-30"), Some(Date::new(2024, 6, 30)));
+    assert_eq!(Date::parse("2024-13-01"), None);
+    assert_eq!(Date::parse("2024-06"), None);
+    assert_eq!(Date::parse("2024-02-31"), None);
+    assert_eq!(Date::parse("2023-02-29"), None);
+    assert_eq!(Date::parse("2024-02-29"), Some(Date::new(2024, 2, 29)));
+    assert_eq!(Date::parse("1900-02-29"), None);
+    assert_eq!(Date::parse("2000-02-29"), Some(Date::new(2000, 2, 29)));
+    assert_eq!(Date::parse("2024-04-31"), None);
+    assert!(Date::new(2024, 6, 30) < Date::new(2024, 7, 1));
+}
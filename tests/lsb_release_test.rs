@@ -1,3 +1,4 @@
+#![allow(dead_code)]
 extern crate regex;
 #[path = "../src/lsb_release.rs"]
 mod lsb_release;
@@ -117,3 +118,15 @@ pub fn test_parses_nixos_lsb_version() {
     let parse_results = lsb_release::parse(nixos_file());
     assert_eq!(parse_results.version, Some("21.11".to_string()));
 }
+
+#[test]
+pub fn test_parses_lsb_codename() {
+    let parse_results = lsb_release::parse(file());
+    assert_eq!(parse_results.codename, Some("wheezy".to_string()));
+}
+
+#[test]
+pub fn test_ignores_unavailable_lsb_codename() {
+    let parse_results = lsb_release::parse(arch_file());
+    assert_eq!(parse_results.codename, None);
+}
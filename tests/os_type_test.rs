@@ -0,0 +1,48 @@
+extern crate os_type;
+
+use os_type::OSType;
+
+#[test]
+pub fn displays_vendor_names() {
+    assert_eq!(OSType::OSX.to_string(), "macOS");
+    assert_eq!(OSType::Redhat.to_string(), "Red Hat Enterprise Linux");
+    assert_eq!(OSType::OpenSUSE.to_string(), "openSUSE");
+    assert_eq!(OSType::DragonFly.to_string(), "DragonFly BSD");
+    assert_eq!(OSType::Android(Some(34)).to_string(), "Android");
+}
+
+#[test]
+pub fn canonical_ids() {
+    assert_eq!(OSType::Redhat.id(), "rhel");
+    assert_eq!(OSType::OSX.id(), "macos");
+    assert_eq!(OSType::Ubuntu.id(), "ubuntu");
+    assert_eq!(OSType::Android(Some(34)).id(), "android");
+}
+
+#[test]
+pub fn parses_ids_names_and_aliases() {
+    assert_eq!("rhel".parse(), Ok(OSType::Redhat));
+    assert_eq!("Red Hat Enterprise Linux".parse(), Ok(OSType::Redhat));
+    assert_eq!("redhat".parse(), Ok(OSType::Redhat));
+    assert_eq!("darwin".parse(), Ok(OSType::OSX));
+    assert_eq!("OSX".parse(), Ok(OSType::OSX));
+    assert_eq!(" Mac OS X ".parse(), Ok(OSType::OSX));
+    assert_eq!("opensuse-tumbleweed".parse(), Ok(OSType::OpenSUSE));
+    assert_eq!("Arch Linux".parse(), Ok(OSType::Arch));
+    assert_eq!("android".parse(), Ok(OSType::Android(None)));
+}
+
+#[test]
+pub fn rejects_unknown_names() {
+    let error = "plan9".parse::<OSType>().unwrap_err();
+    assert_eq!(error.to_string(), "unknown operating system `plan9`");
+    assert!("".parse::<OSType>().is_err());
+}
+
+#[test]
+pub fn all_variants_round_trip() {
+    for os_type in OSType::all() {
+        assert_eq!(os_type.id().parse(), Ok(os_type.clone()));
+        assert_eq!(os_type.to_string().parse(), Ok(os_type));
+    }
+}
@@ -0,0 +1,115 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/package_commands.rs"]
+mod package_commands;
+#[path = "../src/package_manager.rs"]
+mod package_manager;
+#[path = "../src/utils.rs"]
+mod utils;
+
+use package_commands::{PackageCommands, PackageNames};
+use package_manager::PackageManager;
+
+#[test]
+pub fn builds_apt_commands() {
+    let commands = PackageCommands::new(PackageManager::Apt);
+    assert_eq!(commands.install(&["curl", "git"]).unwrap().to_string(), "apt-get install -y curl git");
+    assert_eq!(commands.update().unwrap().to_string(), "apt-get update");
+
+    let installed = commands.is_installed(&["curl", "git"]);
+    assert_eq!(installed.len(), 2);
+    assert_eq!(installed[0].to_string(), "dpkg -s curl");
+    assert_eq!(installed[1].program, "dpkg");
+    assert_eq!(installed[1].args, vec!["-s", "git"]);
+}
+
+#[test]
+pub fn builds_apk_commands() {
+    let commands = PackageCommands::new(PackageManager::Apk);
+    assert_eq!(commands.install(&["curl", "git"]).unwrap().to_string(), "apk add curl git");
+    assert_eq!(commands.update().unwrap().to_string(), "apk update");
+    assert_eq!(commands.is_installed(&["curl"])[0].to_string(), "apk info -e curl");
+}
+
+#[test]
+pub fn translates_package_names() {
+    let dnf = PackageCommands::new(PackageManager::Dnf);
+    assert_eq!(
+        dnf.install(&["python3-dev", "build-essential"]).unwrap().to_string(),
+        "dnf install -y python3-devel gcc gcc-c++ make"
+    );
+
+    let pacman = PackageCommands::new(PackageManager::Pacman);
+    assert_eq!(
+        pacman.install(&["python3-dev", "libssl-dev"]).unwrap().to_string(),
+        "pacman -S --noconfirm --needed python openssl"
+    );
+
+    let apt = PackageCommands::new(PackageManager::Apt);
+    assert_eq!(apt.install(&["python3-dev"]).unwrap().to_string(), "apt-get install -y python3-dev");
+}
+
+#[test]
+pub fn removes_duplicate_names() {
+    let pacman = PackageCommands::new(PackageManager::Pacman);
+    assert_eq!(
+        pacman.install(&["python3-dev", "python"]).unwrap().to_string(),
+        "pacman -S --noconfirm --needed python"
+    );
+}
+
+#[test]
+pub fn allows_extending_the_name_table() {
+    let mut commands = PackageCommands::new(PackageManager::Zypper);
+    commands.names.insert("libffi-dev", PackageManager::Zypper, &["libffi-devel"]);
+    commands.names.insert("python3-dev", PackageManager::Zypper, &["python311-devel"]);
+    assert_eq!(
+        commands.install(&["libffi-dev", "python3-dev"]).unwrap().to_string(),
+        "zypper --non-interactive install libffi-devel python311-devel"
+    );
+}
+
+#[test]
+pub fn skips_packages_mapped_to_nothing() {
+    let mut names = PackageNames::new();
+    names.insert("build-essential", PackageManager::Homebrew, &[]);
+
+    let commands = PackageCommands { manager: PackageManager::Homebrew, names, nix_channel: "nixpkgs".to_string() };
+    assert_eq!(commands.install(&["build-essential", "git"]).unwrap().to_string(), "brew install git");
+    assert_eq!(commands.install(&["build-essential"]), None);
+}
+
+#[test]
+pub fn installs_nothing_without_packages() {
+    assert_eq!(PackageCommands::new(PackageManager::Apt).install(&[]), None);
+}
+
+#[test]
+pub fn prefixes_nix_attributes() {
+    let commands = PackageCommands::new(PackageManager::Nix);
+    assert_eq!(commands.install(&["git"]).unwrap().to_string(), "nix-env -iA nixpkgs.git");
+    assert_eq!(commands.is_installed(&["git"])[0].to_string(), "nix-env -q git");
+}
+
+#[test]
+pub fn uses_the_nixos_channel() {
+    let mut commands = PackageCommands::new(PackageManager::Nix);
+    commands.nix_channel = "nixos".to_string();
+    assert_eq!(commands.install(&["git"]).unwrap().to_string(), "nix-env -iA nixos.git");
+}
+
+#[test]
+pub fn pacman_update_upgrades_the_system() {
+    let commands = PackageCommands::new(PackageManager::Pacman);
+    assert_eq!(commands.update().unwrap().to_string(), "pacman -Syu --noconfirm");
+}
+
+#[test]
+pub fn low_level_tools_cannot_install() {
+    let commands = PackageCommands::new(PackageManager::Rpm);
+    assert_eq!(commands.install(&["curl"]), None);
+    assert_eq!(commands.update(), None);
+    assert_eq!(commands.is_installed(&["curl"])[0].to_string(), "rpm -q curl");
+}
@@ -0,0 +1,106 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/package_manager.rs"]
+mod package_manager;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use package_manager::{PackageFormat, PackageManager};
+use common::fixture;
+
+#[test]
+pub fn detects_apt_with_flatpak_and_snap() {
+    let info = package_manager::detect(&fixture("package_manager", "debian"), None);
+    assert_eq!(info.format, Some(PackageFormat::Deb));
+    assert_eq!(info.native, vec![PackageManager::Apt, PackageManager::Dpkg]);
+    assert_eq!(info.secondary, vec![PackageManager::Flatpak, PackageManager::Snap]);
+}
+
+#[test]
+pub fn detects_dnf() {
+    let info = package_manager::detect(&fixture("package_manager", "fedora"), None);
+    assert_eq!(info.format, Some(PackageFormat::Rpm));
+    assert_eq!(info.native, vec![PackageManager::Dnf, PackageManager::Rpm]);
+    assert!(info.secondary.is_empty());
+}
+
+#[test]
+pub fn detects_zypper_with_sysimage_database() {
+    let info = package_manager::detect(&fixture("package_manager", "opensuse"), None);
+    assert_eq!(info.format, Some(PackageFormat::Rpm));
+    assert_eq!(info.native, vec![PackageManager::Zypper, PackageManager::Rpm]);
+}
+
+#[test]
+pub fn detects_pacman() {
+    let info = package_manager::detect(&fixture("package_manager", "arch"), None);
+    assert_eq!(info.format, Some(PackageFormat::Pacman));
+    assert_eq!(info.native, vec![PackageManager::Pacman]);
+}
+
+#[test]
+pub fn detects_apk() {
+    let info = package_manager::detect(&fixture("package_manager", "alpine"), None);
+    assert_eq!(info.format, Some(PackageFormat::Apk));
+    assert_eq!(info.native, vec![PackageManager::Apk]);
+}
+
+#[test]
+pub fn detects_xbps() {
+    let info = package_manager::detect(&fixture("package_manager", "void"), None);
+    assert_eq!(info.format, Some(PackageFormat::Xbps));
+    assert_eq!(info.native, vec![PackageManager::Xbps]);
+}
+
+#[test]
+pub fn detects_emerge() {
+    let info = package_manager::detect(&fixture("package_manager", "gentoo"), None);
+    assert_eq!(info.format, Some(PackageFormat::Ebuild));
+    assert_eq!(info.native, vec![PackageManager::Emerge]);
+}
+
+#[test]
+pub fn detects_freebsd_pkg() {
+    let info = package_manager::detect(&fixture("package_manager", "freebsd"), None);
+    assert_eq!(info.format, Some(PackageFormat::Pkg));
+    assert_eq!(info.native, vec![PackageManager::Pkg]);
+}
+
+#[test]
+pub fn detects_nix_as_only_manager() {
+    let info = package_manager::detect(&fixture("package_manager", "nixos"), None);
+    assert_eq!(info.format, Some(PackageFormat::Nix));
+    assert_eq!(info.native, vec![PackageManager::Nix]);
+}
+
+#[test]
+pub fn reports_nix_and_homebrew_next_to_dpkg_as_secondary() {
+    let info = package_manager::detect(&fixture("package_manager", "linuxbrew"), None);
+    assert_eq!(info.format, Some(PackageFormat::Deb));
+    assert_eq!(info.native, vec![PackageManager::Dpkg]);
+    assert_eq!(info.secondary, vec![PackageManager::Nix, PackageManager::Homebrew]);
+}
+
+#[test]
+pub fn prefers_the_family_format() {
+    let info = package_manager::detect(&fixture("package_manager", "debian-with-rpm"), None);
+    assert_eq!(info.format, Some(PackageFormat::Deb));
+    assert_eq!(info.secondary, vec![PackageManager::Rpm]);
+
+    let info = package_manager::detect(&fixture("package_manager", "debian-with-rpm"), Some(PackageFormat::Rpm));
+    assert_eq!(info.format, Some(PackageFormat::Rpm));
+    assert_eq!(info.native, vec![PackageManager::Rpm]);
+    assert_eq!(info.secondary, vec![PackageManager::Apt, PackageManager::Dpkg]);
+}
+
+#[test]
+pub fn reports_nothing_without_databases() {
+    let info = package_manager::detect(&fixture("package_manager", "missing"), None);
+    assert_eq!(info.format, None);
+    assert!(info.native.is_empty());
+    assert!(info.secondary.is_empty());
+}
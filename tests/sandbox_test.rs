@@ -0,0 +1,85 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/sandbox.rs"]
+mod sandbox;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use sandbox::Sandbox;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use common::fixture;
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<OsString, OsString> {
+    pairs.iter().map(|&(key, value)| (OsString::from(key), OsString::from(value))).collect()
+}
+
+#[test]
+pub fn detects_flatpak() {
+    let info = sandbox::detect(&fixture("sandbox", "flatpak"), &HashMap::new()).unwrap();
+    assert_eq!(info.sandbox, Sandbox::Flatpak);
+    assert_eq!(info.app_id, Some("org.example.Editor".to_string()));
+    assert_eq!(info.runtime, Some("org.freedesktop.Platform/x86_64/23.08".to_string()));
+    assert_eq!(info.version, Some("1.14.4".to_string()));
+}
+
+#[test]
+pub fn reads_host_os_release_in_flatpak() {
+    let host = sandbox::read_host_os_release(&fixture("sandbox", "flatpak")).unwrap();
+    assert!(host.contains("VERSION_ID=\"22.04\""));
+}
+
+#[test]
+pub fn detects_snap() {
+    let env = vars(&[("SNAP", "/snap/editor/42"), ("SNAP_NAME", "editor"), ("SNAP_REVISION", "42")]);
+    let info = sandbox::detect(&fixture("sandbox", "snap"), &env).unwrap();
+    assert_eq!(info.sandbox, Sandbox::Snap);
+    assert_eq!(info.app_id, Some("editor".to_string()));
+    assert_eq!(info.version, Some("42".to_string()));
+}
+
+#[test]
+pub fn prefers_snap_instance_name() {
+    let env = vars(&[("SNAP_NAME", "editor"), ("SNAP_INSTANCE_NAME", "editor_beta")]);
+    let info = sandbox::detect(&fixture("sandbox", "snap"), &env).unwrap();
+    assert_eq!(info.app_id, Some("editor_beta".to_string()));
+}
+
+#[test]
+pub fn reads_host_os_release_in_snap() {
+    let host = sandbox::read_host_os_release(&fixture("sandbox", "snap")).unwrap();
+    assert!(host.contains("VERSION_CODENAME=bookworm"));
+}
+
+#[test]
+pub fn detects_appimage() {
+    let env = vars(&[("APPIMAGE", "/home/user/Apps/Editor-1.2-x86_64.AppImage"), ("APPDIR", "/tmp/.mount_EditorXyZ")]);
+    let info = sandbox::detect(&fixture("sandbox", "missing"), &env).unwrap();
+    assert_eq!(info.sandbox, Sandbox::AppImage);
+    assert_eq!(info.app_id, Some("Editor-1.2-x86_64".to_string()));
+    assert_eq!(info.version, Some("/home/user/Apps/Editor-1.2-x86_64.AppImage".to_string()));
+}
+
+#[test]
+pub fn reports_nothing_outside_sandboxes() {
+    let env = vars(&[("HOME", "/home/user"), ("SNAP", "")]);
+    assert_eq!(sandbox::detect(&fixture("sandbox", "missing"), &env), None);
+    assert_eq!(sandbox::read_host_os_release(&fixture("sandbox", "missing")), None);
+}
+
+#[cfg(unix)]
+#[test]
+pub fn accepts_variables_which_are_not_utf8() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut env = vars(&[("SNAP_NAME", "editor")]);
+    env.insert(OsString::from("BADVAR"), OsString::from_vec(vec![0xff, 0xfe]));
+    env.insert(OsString::from("SNAP_REVISION"), OsString::from_vec(vec![b'4', 0xff]));
+    let info = sandbox::detect(&fixture("sandbox", "snap"), &env).unwrap();
+    assert_eq!(info.app_id, Some("editor".to_string()));
+    assert_eq!(info.version, Some("4\u{fffd}".to_string()));
+}
@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/efivars.rs"]
+mod efivars;
+#[path = "../src/security.rs"]
+mod security;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use security::{LockdownMode, SELinuxMode};
+use common::fixture;
+
+#[test]
+pub fn detects_hardened_fedora() {
+    let info = security::detect(&fixture("security", "fedora"));
+    let selinux = info.selinux.unwrap();
+    assert_eq!(selinux.mode, SELinuxMode::Enforcing);
+    assert_eq!(selinux.configured_mode, Some(SELinuxMode::Enforcing));
+    assert_eq!(selinux.policy, Some("targeted".to_string()));
+    assert!(!info.apparmor);
+    assert!(info.fips);
+    assert_eq!(info.lockdown, Some(LockdownMode::Integrity));
+    assert_eq!(info.secure_boot, Some(true));
+}
+
+#[test]
+pub fn detects_apparmor_without_secure_boot() {
+    let info = security::detect(&fixture("security", "ubuntu"));
+    assert_eq!(info.selinux, None);
+    assert!(info.apparmor);
+    assert!(!info.fips);
+    assert_eq!(info.lockdown, Some(LockdownMode::Disabled));
+    assert_eq!(info.secure_boot, Some(false));
+}
+
+#[test]
+pub fn detects_disabled_selinux() {
+    let info = security::detect(&fixture("security", "selinux-disabled"));
+    let selinux = info.selinux.unwrap();
+    assert_eq!(selinux.mode, SELinuxMode::Disabled);
+    assert_eq!(selinux.configured_mode, Some(SELinuxMode::Disabled));
+    assert_eq!(info.lockdown, None);
+    assert_eq!(info.secure_boot, None);
+}
+
+#[test]
+pub fn parses_lockdown_modes() {
+    assert_eq!(security::parse_lockdown("none integrity [confidentiality]\n"), Some(LockdownMode::Confidentiality));
+    assert_eq!(security::parse_lockdown("none integrity confidentiality"), None);
+}
+
+#[test]
+pub fn parses_selinux_modes() {
+    assert_eq!(security::parse_selinux_mode("Permissive"), Some(SELinuxMode::Permissive));
+    assert_eq!(security::parse_selinux_mode("off"), None);
+}
@@ -0,0 +1,316 @@
+#![cfg(feature = "serde")]
+extern crate os_type;
+extern crate serde;
+extern crate serde_json;
+
+use os_type::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn assert_round_trips<T>(values: &[(T, &str)])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    for (value, expected) in values {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(json, format!("\"{}\"", expected));
+        assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+pub fn round_trips_os_types_as_ids() {
+    let os_types: Vec<(OSType, &str)> = OSType::all().map(|os_type| {
+        let id = os_type.id();
+        (os_type, id)
+    }).collect();
+    assert_round_trips(&os_types);
+    assert_round_trips(&[(OSType::Redhat, "rhel"), (OSType::OSX, "macos"), (OSType::NixOS, "nixos")]);
+}
+
+#[test]
+pub fn round_trips_android_api_levels() {
+    assert_round_trips(&[(OSType::Android(Some(34)), "android-34"), (OSType::Android(None), "android")]);
+    assert!(serde_json::from_str::<OSType>(r#""android-""#).is_err());
+    assert!(serde_json::from_str::<OSType>(r#""android-u""#).is_err());
+}
+
+#[test]
+pub fn rejects_os_type_aliases() {
+    assert!(serde_json::from_str::<OSType>(r#""Ubuntu""#).is_err());
+    assert!(serde_json::from_str::<OSType>(r#""darwin""#).is_err());
+    assert!(serde_json::from_str::<OSType>(r#"{"android":34}"#).is_err());
+}
+
+#[test]
+pub fn round_trips_os_families() {
+    assert_round_trips(&[
+        (OSFamily::Unknown, "unknown"),
+        (OSFamily::Debian, "debian"),
+        (OSFamily::RedHat, "rhel"),
+        (OSFamily::Suse, "suse"),
+        (OSFamily::Arch, "arch"),
+        (OSFamily::Alpine, "alpine"),
+        (OSFamily::NixOS, "nixos"),
+        (OSFamily::BSD, "bsd"),
+        (OSFamily::Solaris, "solaris"),
+        (OSFamily::Darwin, "darwin"),
+        (OSFamily::Android, "android"),
+        (OSFamily::ChromeOS, "chromeos"),
+        (OSFamily::Haiku, "haiku"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_architectures() {
+    assert_round_trips(&[
+        (Architecture::X86, "x86"),
+        (Architecture::X86_64, "x86_64"),
+        (Architecture::Arm, "arm"),
+        (Architecture::Aarch64, "aarch64"),
+        (Architecture::PowerPC, "powerpc"),
+        (Architecture::PowerPC64, "powerpc64"),
+        (Architecture::RiscV32, "riscv32"),
+        (Architecture::RiscV64, "riscv64"),
+        (Architecture::S390x, "s390x"),
+        (Architecture::Mips, "mips"),
+        (Architecture::Mips64, "mips64"),
+        (Architecture::LoongArch64, "loongarch64"),
+        (Architecture::Other("sparc64".to_string()), "sparc64"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_cloud_providers() {
+    assert_round_trips(&[
+        (CloudProvider::AWS, "aws"),
+        (CloudProvider::GCE, "gce"),
+        (CloudProvider::Azure, "azure"),
+        (CloudProvider::OracleCloud, "oracle"),
+        (CloudProvider::DigitalOcean, "digital-ocean"),
+        (CloudProvider::Hetzner, "hetzner"),
+        (CloudProvider::Alibaba, "alibaba"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_container_types() {
+    assert_round_trips(&[
+        (ContainerRuntime::Docker, "docker"),
+        (ContainerRuntime::Podman, "podman"),
+        (ContainerRuntime::LXC, "lxc"),
+        (ContainerRuntime::SystemdNspawn, "systemd-nspawn"),
+        (ContainerRuntime::Containerd, "containerd"),
+        (ContainerRuntime::CRIO, "cri-o"),
+        (ContainerRuntime::Other("garden".to_string()), "garden"),
+        (ContainerRuntime::Unknown, "unknown"),
+    ]);
+    assert_round_trips(&[(CGroupVersion::V1, "v1"), (CGroupVersion::V2, "v2")]);
+    assert_round_trips(&[(ContainerTool::Toolbox, "toolbox"), (ContainerTool::Distrobox, "distrobox")]);
+}
+
+#[test]
+pub fn round_trips_firmware_types() {
+    assert_round_trips(&[(BootMode::UEFI, "uefi"), (BootMode::BIOS, "bios"), (BootMode::Unknown, "unknown")]);
+    assert_round_trips(&[
+        (Bootloader::SystemdBoot, "systemd-boot"),
+        (Bootloader::Grub, "grub"),
+        (Bootloader::Other("rEFInd".to_string()), "rEFInd"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_immutable_systems() {
+    assert_round_trips(&[
+        (ImmutableOS::FedoraAtomic, "fedora-atomic"),
+        (ImmutableOS::FedoraCoreOS, "fedora-coreos"),
+        (ImmutableOS::RHCOS, "rhcos"),
+        (ImmutableOS::Flatcar, "flatcar"),
+        (ImmutableOS::Bottlerocket, "bottlerocket"),
+        (ImmutableOS::Talos, "talos"),
+        (ImmutableOS::OSTree, "ostree"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_init_systems() {
+    assert_round_trips(&[
+        (InitSystem::Systemd, "systemd"),
+        (InitSystem::OpenRC, "openrc"),
+        (InitSystem::Runit, "runit"),
+        (InitSystem::S6, "s6"),
+        (InitSystem::SysVinit, "sysvinit"),
+        (InitSystem::Launchd, "launchd"),
+        (InitSystem::NoInit, "none"),
+        (InitSystem::Unknown, "unknown"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_kernel_flavours() {
+    assert_round_trips(&[
+        (KernelFlavour::Generic, "generic"),
+        (KernelFlavour::LowLatency, "low-latency"),
+        (KernelFlavour::RealTime, "real-time"),
+        (KernelFlavour::AWS, "aws"),
+        (KernelFlavour::Azure, "azure"),
+        (KernelFlavour::WSL, "wsl"),
+        (KernelFlavour::Grsecurity, "grsecurity"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_c_libraries() {
+    assert_round_trips(&[
+        (LibC::Glibc, "glibc"),
+        (LibC::Musl, "musl"),
+        (LibC::Bionic, "bionic"),
+        (LibC::UClibc, "uclibc"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_lifecycle_types() {
+    assert_round_trips(&[
+        (ExtendedSupport::ESM, "esm"),
+        (ExtendedSupport::LTS, "lts"),
+        (ExtendedSupport::ELS, "els"),
+        (ExtendedSupport::SecurityUpdates, "security-updates"),
+    ]);
+    assert_round_trips(&[
+        (LifecycleStatus::Supported, "supported"),
+        (LifecycleStatus::SecurityOnly, "security-only"),
+        (LifecycleStatus::EOL, "eol"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_package_managers() {
+    assert_round_trips(&[
+        (PackageManager::Apt, "apt"),
+        (PackageManager::Dpkg, "dpkg"),
+        (PackageManager::Dnf, "dnf"),
+        (PackageManager::Yum, "yum"),
+        (PackageManager::Zypper, "zypper"),
+        (PackageManager::Rpm, "rpm"),
+        (PackageManager::Pacman, "pacman"),
+        (PackageManager::Apk, "apk"),
+        (PackageManager::Xbps, "xbps"),
+        (PackageManager::Emerge, "emerge"),
+        (PackageManager::Pkg, "pkg"),
+        (PackageManager::Nix, "nix"),
+        (PackageManager::Homebrew, "homebrew"),
+        (PackageManager::Flatpak, "flatpak"),
+        (PackageManager::Snap, "snap"),
+    ]);
+    assert_round_trips(&[
+        (PackageFormat::Deb, "deb"),
+        (PackageFormat::Rpm, "rpm"),
+        (PackageFormat::Pacman, "pacman"),
+        (PackageFormat::Apk, "apk"),
+        (PackageFormat::Xbps, "xbps"),
+        (PackageFormat::Ebuild, "ebuild"),
+        (PackageFormat::Pkg, "pkg"),
+        (PackageFormat::Nix, "nix"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_sandboxes() {
+    assert_round_trips(&[
+        (Sandbox::Flatpak, "flatpak"),
+        (Sandbox::Snap, "snap"),
+        (Sandbox::AppImage, "app-image"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_security_modes() {
+    assert_round_trips(&[
+        (SELinuxMode::Enforcing, "enforcing"),
+        (SELinuxMode::Permissive, "permissive"),
+        (SELinuxMode::Disabled, "disabled"),
+    ]);
+    assert_round_trips(&[
+        (LockdownMode::Disabled, "none"),
+        (LockdownMode::Integrity, "integrity"),
+        (LockdownMode::Confidentiality, "confidentiality"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_macos_product_names() {
+    assert_round_trips(&[
+        (MacOSProductName::MacOSX, "mac-os-x"),
+        (MacOSProductName::OSX, "os-x"),
+        (MacOSProductName::MacOS, "mac-os"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_ubuntu_types() {
+    assert_round_trips(&[
+        (UbuntuReleaseType::LTS, "lts"),
+        (UbuntuReleaseType::Interim, "interim"),
+        (UbuntuReleaseType::Development, "development"),
+    ]);
+    assert_round_trips(&[
+        (UbuntuFlavour::Desktop, "desktop"),
+        (UbuntuFlavour::Server, "server"),
+        (UbuntuFlavour::Core, "core"),
+        (UbuntuFlavour::Kubuntu, "kubuntu"),
+        (UbuntuFlavour::Xubuntu, "xubuntu"),
+        (UbuntuFlavour::Lubuntu, "lubuntu"),
+        (UbuntuFlavour::UbuntuMATE, "ubuntu-mate"),
+        (UbuntuFlavour::UbuntuBudgie, "ubuntu-budgie"),
+        (UbuntuFlavour::UbuntuStudio, "ubuntu-studio"),
+        (UbuntuFlavour::UbuntuUnity, "ubuntu-unity"),
+        (UbuntuFlavour::UbuntuKylin, "ubuntu-kylin"),
+        (UbuntuFlavour::UbuntuCinnamon, "ubuntu-cinnamon"),
+        (UbuntuFlavour::Edubuntu, "edubuntu"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_virtualization() {
+    assert_round_trips(&[
+        (Virtualization::BareMetal, "bare-metal"),
+        (Virtualization::KVM, "kvm"),
+        (Virtualization::QEMU, "qemu"),
+        (Virtualization::VMware, "vmware"),
+        (Virtualization::HyperV, "hyper-v"),
+        (Virtualization::Xen, "xen"),
+        (Virtualization::VirtualBox, "virtual-box"),
+        (Virtualization::Parallels, "parallels"),
+        (Virtualization::Bhyve, "bhyve"),
+        (Virtualization::Other, "other"),
+        (Virtualization::Unknown, "unknown"),
+    ]);
+}
+
+#[test]
+pub fn round_trips_wsl_generations() {
+    assert_round_trips(&[(WSLGeneration::WSL1, "wsl1"), (WSLGeneration::WSL2, "wsl2")]);
+}
+
+#[test]
+pub fn prefers_known_names_over_other_values() {
+    assert_eq!(serde_json::from_str::<ContainerRuntime>(r#""docker""#).unwrap(), ContainerRuntime::Docker);
+    assert_eq!(serde_json::from_str::<Architecture>(r#""x86_64""#).unwrap(), Architecture::X86_64);
+}
+
+#[test]
+pub fn serializes_dates_in_iso_form() {
+    let date = Date::new(2024, 6, 30);
+    assert_eq!(serde_json::to_string(&date).unwrap(), r#""2024-06-30""#);
+    assert_eq!(serde_json::from_str::<Date>(r#""2024-06-30""#).unwrap(), date);
+    assert!(serde_json::from_str::<Date>(r#""yesterday""#).is_err());
+}
+
+#[test]
+pub fn round_trips_the_current_platform() {
+    let os = os_type::current_platform();
+    let json = serde_json::to_string(&os).unwrap();
+    assert_eq!(serde_json::from_str::<os_type::OSInformation>(&json).unwrap(), os);
+}
@@ -1,3 +1,7 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 extern crate regex;
 #[path="../src/sw_vers.rs"]
 mod sw_vers;
@@ -29,3 +33,41 @@ pub fn parses_build_version() {
     let info = sw_vers::parse(file());
     assert_eq!(info.build_version, Some("14F27".to_string()));
 }
+
+fn monterey_file() -> String {
+"
+ProductName:	macOS
+ProductVersion:	12.6
+BuildVersion:	21G115
+".to_string()
+}
+
+fn server_file() -> String {
+"
+ProductName:	Mac OS X Server
+ProductVersion:	10.6.8
+BuildVersion:	10K549
+".to_string()
+}
+
+#[test]
+pub fn parses_two_component_product_version() {
+    let info = sw_vers::parse(monterey_file());
+    assert_eq!(info.product_name, Some("macOS".to_string()));
+    assert_eq!(info.product_version, Some("12.6".to_string()));
+}
+
+#[test]
+pub fn detects_server_product_name() {
+    let info = sw_vers::parse(server_file());
+    assert!(sw_vers::is_server(&info.product_name.unwrap()));
+    assert!(!sw_vers::is_server("Mac OS X"));
+}
+
+#[test]
+pub fn distinguishes_product_names() {
+    assert_eq!(sw_vers::product_name("10.6.8"), sw_vers::MacOSProductName::MacOSX);
+    assert_eq!(sw_vers::product_name("10.10.5"), sw_vers::MacOSProductName::OSX);
+    assert_eq!(sw_vers::product_name("10.15.7"), sw_vers::MacOSProductName::MacOS);
+    assert_eq!(sw_vers::product_name("14.4"), sw_vers::MacOSProductName::MacOS);
+}
@@ -0,0 +1,111 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/json.rs"]
+mod json;
+#[path = "../src/ubuntu.rs"]
+mod ubuntu;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "common/mod.rs"]
+mod common;
+
+use common::fixture;
+use ubuntu::{UbuntuFlavour, UbuntuReleaseType};
+
+#[test]
+pub fn detects_interim_kubuntu() {
+    let info = ubuntu::detect(&fixture("ubuntu", "kubuntu"), "23.10", Some("6.5.0-9-generic"));
+    assert_eq!(info.release_type, UbuntuReleaseType::Interim);
+    assert_eq!(info.flavour, Some(UbuntuFlavour::Kubuntu));
+    assert!(!info.hwe);
+    assert_eq!(info.pro, None);
+}
+
+#[test]
+pub fn detects_lts_server_with_hwe_and_pro() {
+    let info = ubuntu::detect(&fixture("ubuntu", "server-hwe"), "22.04", Some("6.5.0-14-generic"));
+    assert_eq!(info.release_type, UbuntuReleaseType::LTS);
+    assert_eq!(info.flavour, Some(UbuntuFlavour::Server));
+    assert!(info.hwe);
+
+    let pro = info.pro.unwrap();
+    assert!(pro.attached);
+    assert_eq!(pro.esm_services, vec!["esm-apps".to_string(), "esm-infra".to_string()]);
+}
+
+#[test]
+pub fn detects_ubuntu_core() {
+    let info = ubuntu::detect(&fixture("ubuntu", "core"), "22", None);
+    assert_eq!(info.flavour, Some(UbuntuFlavour::Core));
+}
+
+#[test]
+pub fn detects_development_release() {
+    let info = ubuntu::detect(&fixture("ubuntu", "devel"), "24.10", None);
+    assert_eq!(info.release_type, UbuntuReleaseType::Development);
+    assert_eq!(info.flavour, None);
+}
+
+#[test]
+pub fn classifies_release_types() {
+    assert_eq!(ubuntu::release_type("6.06", ""), UbuntuReleaseType::LTS);
+    assert_eq!(ubuntu::release_type("20.04", ""), UbuntuReleaseType::LTS);
+    assert_eq!(ubuntu::release_type("24.04.1", ""), UbuntuReleaseType::LTS);
+    assert_eq!(ubuntu::release_type("21.04", ""), UbuntuReleaseType::Interim);
+    assert_eq!(ubuntu::release_type("22.10", ""), UbuntuReleaseType::Interim);
+}
+
+#[test]
+pub fn parses_flavour_descriptions() {
+    assert_eq!(ubuntu::parse_flavour_description("Xubuntu 22.04.3 LTS"), Some(UbuntuFlavour::Xubuntu));
+    assert_eq!(ubuntu::parse_flavour_description("Ubuntu Core 22"), Some(UbuntuFlavour::Core));
+    assert_eq!(ubuntu::parse_flavour_description("Ubuntu 22.04.3 LTS"), None);
+}
+
+#[test]
+pub fn ignores_ga_kernel_with_hwe_metapackage() {
+    let info = ubuntu::detect(&fixture("ubuntu", "server-hwe"), "22.04", Some("5.15.0-91-generic"));
+    assert!(!info.hwe);
+}
+
+#[test]
+pub fn compares_kernel_with_hwe_series() {
+    let status = "Package: linux-generic-hwe-22.04\nStatus: install ok installed\nVersion: 6.8.0.45.45~22.04.29\n";
+    assert_eq!(ubuntu::parse_hwe_series(status), Some((6, 8)));
+    assert_eq!(ubuntu::parse_hwe_series("Package: linux-generic\nStatus: install ok installed\nVersion: 5.15.0.91.88\n"), None);
+    assert!(ubuntu::is_hwe(Some((6, 8)), Some("6.8.0-45-generic")));
+    assert!(!ubuntu::is_hwe(Some((6, 8)), Some("6.9.0-060900-generic")));
+    assert!(!ubuntu::is_hwe(None, Some("6.8.0-45-generic")));
+    assert!(!ubuntu::is_hwe(Some((6, 8)), None));
+}
+
+#[test]
+pub fn parses_detached_pro_status() {
+    let pro = ubuntu::parse_pro_status(r#"{"attached": false, "services": [{"name": "esm-infra", "status": "n/a"}]}"#);
+    assert!(!pro.attached);
+    assert!(pro.esm_services.is_empty());
+}
+
+#[test]
+pub fn reads_only_top_level_pro_fields() {
+    let status = r#"{
+        "account": {"attached": true, "services": [{"name": "esm-nested", "status": "enabled"}]},
+        "attached": false,
+        "services": [
+            {"name": "esm-infra", "status": "enabled", "details": {"status": "disabled"}},
+            {"name": "esm-apps", "status": "disabled", "entitlement": {"status": "enabled"}}
+        ]
+    }"#;
+    let pro = ubuntu::parse_pro_status(status);
+    assert!(!pro.attached);
+    assert_eq!(pro.esm_services, vec!["esm-infra".to_string()]);
+}
+
+#[test]
+pub fn ignores_malformed_pro_status() {
+    let pro = ubuntu::parse_pro_status(r#"{"attached": true, "services": ["#);
+    assert!(!pro.attached);
+    assert!(pro.esm_services.is_empty());
+}
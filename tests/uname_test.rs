@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+#[path = "../src/uname.rs"]
+mod uname;
+
+#[test]
+pub fn parses_freebsd_uname() {
+    let info = uname::parse("FreeBSD 13.2-RELEASE-p4 amd64\n".to_string());
+    assert_eq!(info.sysname, Some("FreeBSD".to_string()));
+    assert_eq!(info.release, Some("13.2-RELEASE-p4".to_string()));
+    assert_eq!(info.machine, Some("amd64".to_string()));
+}
+
+#[test]
+pub fn parses_openbsd_uname() {
+    let info = uname::parse("OpenBSD 7.4 amd64\n".to_string());
+    assert_eq!(info.sysname, Some("OpenBSD".to_string()));
+    assert_eq!(info.release, Some("7.4".to_string()));
+}
+
+#[test]
+pub fn parses_netbsd_uname() {
+    let info = uname::parse("NetBSD 9.3 amd64\n".to_string());
+    assert_eq!(info.sysname, Some("NetBSD".to_string()));
+    assert_eq!(info.release, Some("9.3".to_string()));
+}
+
+#[test]
+pub fn parses_dragonfly_uname() {
+    let info = uname::parse("DragonFly 6.4-RELEASE x86_64\n".to_string());
+    assert_eq!(info.sysname, Some("DragonFly".to_string()));
+    assert_eq!(info.release, Some("6.4-RELEASE".to_string()));
+}
+
+#[test]
+pub fn parses_empty_uname() {
+    let info = uname::parse("".to_string());
+    assert_eq!(info.sysname, None);
+    assert_eq!(info.release, None);
+    assert_eq!(info.machine, None);
+}
+
+#[test]
+pub fn parses_haiku_uname() {
+    let info = uname::parse("Haiku 1 x86_64\n".to_string());
+    assert_eq!(info.sysname, Some("Haiku".to_string()));
+    assert_eq!(info.release, Some("1".to_string()));
+}
+
+#[test]
+pub fn parses_haiku_revision() {
+    let revision = uname::parse_haiku_revision("hrev57937 Apr 15 2024 07:06:05");
+    assert_eq!(revision, Some("hrev57937".to_string()));
+}
+
+#[test]
+pub fn ignores_missing_haiku_revision() {
+    let revision = uname::parse_haiku_revision("#1 SMP PREEMPT_DYNAMIC");
+    assert_eq!(revision, None);
+}
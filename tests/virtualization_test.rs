@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "../src/virtualization.rs"]
+mod virtualization;
+#[path = "common/mod.rs"]
+mod common;
+
+use common::fixture;
+use virtualization::Virtualization;
+
+#[test]
+pub fn detects_kvm() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "kvm")), Virtualization::KVM);
+}
+
+#[test]
+pub fn detects_qemu_without_kvm() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "qemu")), Virtualization::QEMU);
+}
+
+#[test]
+pub fn detects_vmware() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "vmware")), Virtualization::VMware);
+}
+
+#[test]
+pub fn detects_hyperv() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "hyperv")), Virtualization::HyperV);
+}
+
+#[test]
+pub fn detects_virtualbox() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "virtualbox")), Virtualization::VirtualBox);
+}
+
+#[test]
+pub fn detects_parallels() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "parallels")), Virtualization::Parallels);
+}
+
+#[test]
+pub fn detects_bhyve() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "bhyve")), Virtualization::Bhyve);
+}
+
+#[test]
+pub fn detects_xen_paravirtualized() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "xen")), Virtualization::Xen);
+}
+
+#[test]
+pub fn detects_unidentified_hypervisor() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "unidentified")), Virtualization::Other);
+}
+
+#[test]
+pub fn detects_bare_metal() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "baremetal")), Virtualization::BareMetal);
+}
+
+#[test]
+pub fn does_not_mistake_surface_for_hyperv() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "surface")), Virtualization::BareMetal);
+}
+
+#[test]
+pub fn detects_nothing_without_sysfs() {
+    assert_eq!(virtualization::detect(&fixture("virtualization", "missing")), Virtualization::Unknown);
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[path = "../src/utils.rs"]
+mod utils;
+#[path = "../src/wsl.rs"]
+mod wsl;
+
+use wsl::WSLGeneration;
+
+#[test]
+pub fn detects_wsl1_osrelease() {
+    let generation = wsl::parse_kernel_release("4.4.0-19041-Microsoft\n");
+    assert_eq!(generation, Some(WSLGeneration::WSL1));
+}
+
+#[test]
+pub fn detects_wsl1_proc_version() {
+    let generation = wsl::parse_kernel_release(
+        "Linux version 4.4.0-19041-Microsoft (Microsoft@Microsoft.com) (gcc version 5.4.0 (GCC) ) #1237-Microsoft Sat Sep 11 14:32:00 PST 2021\n",
+    );
+    assert_eq!(generation, Some(WSLGeneration::WSL1));
+}
+
+#[test]
+pub fn detects_wsl2_osrelease() {
+    let generation = wsl::parse_kernel_release("5.15.133.1-microsoft-standard-WSL2\n");
+    assert_eq!(generation, Some(WSLGeneration::WSL2));
+}
+
+#[test]
+pub fn detects_early_wsl2_osrelease() {
+    let generation = wsl::parse_kernel_release("4.19.104-microsoft-standard\n");
+    assert_eq!(generation, Some(WSLGeneration::WSL2));
+}
+
+#[test]
+pub fn ignores_regular_kernel() {
+    let generation = wsl::parse_kernel_release("6.5.0-27-generic\n");
+    assert_eq!(generation, None);
+}